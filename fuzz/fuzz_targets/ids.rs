@@ -0,0 +1,12 @@
+#![no_main]
+
+use lalamove_rs::{QuotationId, StopId};
+use libfuzzer_sys::fuzz_target;
+use serde_json::{from_str, to_string};
+
+fuzz_target!(|ids: (QuotationId, StopId)| {
+    let serialized = to_string(&ids).unwrap();
+    let deserialized = from_str::<(QuotationId, StopId)>(&serialized).unwrap();
+
+    assert_eq!(ids, deserialized);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use lalamove_rs::{DeliveryStatus, Location};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = data.parse::<Location>();
+    let _ = data.parse::<DeliveryStatus>();
+});
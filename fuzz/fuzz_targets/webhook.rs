@@ -0,0 +1,10 @@
+#![no_main]
+
+use lalamove_rs::{Webhook, WebhookVerifier};
+use libfuzzer_sys::fuzz_target;
+use serde_json::from_slice;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = from_slice::<Webhook>(data);
+    let _ = WebhookVerifier::new("sk_test_fuzz").verify::<Webhook>("/webhook", data);
+});
@@ -1,109 +1,206 @@
-use serde_json::{from_str, Value};
+// Regenerate the Philippine service enums with:
+// cargo run | rustfmt --edition 2021 > ../src/markets/philippine_services.rs
+
+use serde::Deserialize;
+use serde_json::from_str;
 use std::{
-    collections::HashMap,
-    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    collections::{BTreeMap, BTreeSet},
     fs::read_to_string,
 };
 
-trait Token: Display + Debug {}
-
-impl Token for &'static str {}
-impl Token for String {}
-
-struct Property {
-    name: String,
-    value: Box<dyn Token>,
+#[derive(Deserialize)]
+struct City {
+    services: Vec<Service>,
 }
 
-impl Property {
-    fn new<T: Token + Clone + 'static>(name: impl Into<String>, value: T) -> Self {
-        Property {
-            name: name.into(),
-            value: Box::new(value),
-        }
-    }
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Service {
+    key: String,
+    special_requests: Vec<SpecialRequest>,
 }
 
-struct Variant {
+#[derive(Deserialize)]
+struct SpecialRequest {
     name: String,
-    properties: Vec<Property>,
 }
 
-impl Variant {
-    fn new(name: impl Into<String>, properties: Vec<Property>) -> Self {
-        Variant {
-            name: name.into(),
-            properties,
-        }
-    }
-}
-
-struct Enum {
-    name: String,
-    variants: Vec<Variant>,
-}
-
-impl Display for Enum {
-    fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        writeln!(f, "enum {} {{", self.name)?;
-
-        let variants = &self.variants;
-
-        for variant in variants {
-            writeln!(f, "   {},", variant.name)?;
-        }
+fn variant_name(key: &str) -> String {
+    let mut name = String::new();
 
-        writeln!(f, "}}\n")?;
+    for word in key.split('_') {
+        let mut chars = word.chars().peekable();
 
-        writeln!(f, "impl {} {{", self.name)?;
+        while let Some(first) = chars.next() {
+            let is_digit = first.is_ascii_digit();
+            let mut run = String::from(first);
 
-        let mut variant_properties = HashMap::new();
+            while let Some(next) = chars.next_if(|next| next.is_ascii_digit() == is_digit) {
+                run.push(next);
+            }
 
-        for variant in variants {
-            for property in &variant.properties {
-                let property_values = variant_properties
-                    .entry(&property.name)
-                    .or_insert_with(|| HashMap::new());
-                property_values.insert(&variant.name, &property.value);
+            if is_digit {
+                name.push_str(&run);
+            } else {
+                name.push_str(&run[..1].to_uppercase());
+                name.push_str(&run[1..].to_lowercase());
             }
         }
+    }
 
-        for (property_name, property_values) in variant_properties {
-            writeln!(f, "   fn {property_name}(&self) -> () {{");
-
-            writeln!(f, "       match &self");
+    let digits = name.chars().take_while(char::is_ascii_digit).count();
 
-            writeln!(f, "}}");
-        }
+    if digits == 0 {
+        return name;
+    }
 
-        writeln!(f, "}}")
+    match &name[..digits] {
+        "10" => format!("Ten{}", &name[digits..]),
+        _ => format!("N{name}"),
     }
 }
 
-impl Enum {
-    fn new(name: impl Into<String>, variants: Vec<Variant>) -> Self {
-        Enum {
-            name: name.into(),
-            variants,
-        }
+fn alias(name: &str) -> String {
+    name.chars().filter(char::is_ascii_uppercase).collect()
+}
+
+fn print_enum<'a>(
+    name: &str,
+    wire_type: &str,
+    fallback: &str,
+    keys: impl IntoIterator<Item = &'a String> + Clone,
+) {
+    let alias = alias(name);
+
+    println!("#[derive(Debug, Clone, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]");
+    println!("pub enum {name} {{");
+    for key in keys.clone() {
+        println!("{},", variant_name(key));
+    }
+    println!("{fallback}(String),");
+    println!("}}");
+    println!();
+
+    println!("impl {name} {{");
+    println!("pub fn from_key(key: &str) -> Self {{");
+    println!("use {name} as {alias};");
+    println!();
+    println!("match key {{");
+    for key in keys.clone() {
+        println!("\"{key}\" => {alias}::{},", variant_name(key));
     }
+    println!("_ => {alias}::{fallback}(key.to_string()),");
+    println!("}}");
+    println!("}}");
+    println!();
+
+    println!("pub fn key(&self) -> &str {{");
+    println!("use {name} as {alias};");
+    println!();
+    println!("match self {{");
+    for key in keys {
+        println!("{alias}::{} => \"{key}\",", variant_name(key));
+    }
+    println!("{alias}::{fallback}(key) => key,");
+    println!("}}");
+    println!("}}");
+    println!("}}");
+    println!();
+
+    println!("impl Display for {name} {{");
+    println!("fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {{");
+    println!("write!(formatter, \"{{}}\", self.key())");
+    println!("}}");
+    println!("}}");
+    println!();
+
+    println!("impl FromStr for {name} {{");
+    println!("type Err = Infallible;");
+    println!();
+    println!("fn from_str(key: &str) -> Result<Self, Self::Err> {{");
+    println!("Ok({name}::from_key(key))");
+    println!("}}");
+    println!("}}");
+    println!();
+
+    println!("impl From<{name}> for {wire_type} {{");
+    println!("fn from(value: {name}) -> Self {{");
+    println!("{wire_type}(value.key().to_string())");
+    println!("}}");
+    println!("}}");
+    println!();
+
+    println!("impl From<{wire_type}> for {name} {{");
+    println!("fn from(value: {wire_type}) -> Self {{");
+    println!("{name}::from_key(&value.0)");
+    println!("}}");
+    println!("}}");
+    println!();
+
+    println!("impl From<&{wire_type}> for {name} {{");
+    println!("fn from(value: &{wire_type}) -> Self {{");
+    println!("{name}::from_key(&value.0)");
+    println!("}}");
+    println!("}}");
+    println!();
 }
 
 fn main() {
-    // let market_info_str = read_to_string("./market_info.json").unwrap();
-    // let market_info_json = from_str::<Value>(&market_info_str).unwrap();
-
-    // println!("{market_info_json:?}");
-
-    let cardinal_directions = Enum::new(
-        "CardinalDirections",
-        vec![
-            Variant::new("North", vec![Property::new("North", "fuck")]),
-            Variant::new("South", vec![]),
-            Variant::new("East", vec![]),
-            Variant::new("West", vec![]),
-        ],
+    let market_info_str = read_to_string("./market_info.json").unwrap();
+    let cities = from_str::<Vec<City>>(&market_info_str).unwrap();
+
+    let mut services = BTreeMap::<String, BTreeSet<String>>::new();
+
+    for service in cities.into_iter().flat_map(|city| city.services) {
+        services.entry(service.key).or_default().extend(
+            service
+                .special_requests
+                .into_iter()
+                .map(|special_request| special_request.name),
+        );
+    }
+
+    let special_requests = services.values().flatten().collect::<BTreeSet<_>>();
+
+    println!("// This file was generated by the `parsing` crate from `parsing/market_info.json`.");
+    println!();
+    println!("use std::{{convert::Infallible, fmt::{{Display, Formatter, Result as FmtResult}}, str::FromStr}};");
+    println!();
+    println!("use serde_with::{{DeserializeFromStr, SerializeDisplay}};");
+    println!();
+    println!("use crate::markets::{{ServiceType, SpecialRequestType}};");
+    println!();
+
+    print_enum(
+        "PhilippineServices",
+        "ServiceType",
+        "Custom",
+        services.keys(),
+    );
+    print_enum(
+        "PhilippineSpecialRequests",
+        "SpecialRequestType",
+        "Other",
+        special_requests.iter().copied(),
     );
 
-    println!("{}", cardinal_directions)
+    println!("impl PhilippineServices {{");
+    println!("pub fn special_requests(&self) -> Vec<PhilippineSpecialRequests> {{");
+    println!("use PhilippineServices as PS;");
+    println!("use PhilippineSpecialRequests as PSR;");
+    println!();
+    println!("match self {{");
+    for (key, special_requests) in &services {
+        let special_requests = special_requests
+            .iter()
+            .map(|special_request| format!("PSR::{}", variant_name(special_request)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        println!("PS::{} => vec![{special_requests}],", variant_name(key));
+    }
+    println!("PS::Custom(_) => Vec::new(),");
+    println!("}}");
+    println!("}}");
+    println!("}}");
 }
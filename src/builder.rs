@@ -0,0 +1,269 @@
+use chrono::{DateTime, Utc};
+use rusty_money::{iso::Currency, Money};
+use serde_json::{Map, Value};
+
+use crate::{
+    valid_recipient_stop_count, Assert, DeliveryRequest, DriverId, IsTrue, Item, Location,
+    PaymentMethod, PersonInfo, QuotationRequest, QuotedRequest, ServiceType,
+};
+
+/// Marks a builder field that hasn't been set yet, so `build` isn't available.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Unset;
+
+/// Builds a [`QuotationRequest`] one field at a time.
+///
+/// `build` only exists once a service, a pick up location and at least one stop have been set, so
+/// an incomplete request doesn't compile.
+#[derive(Debug, Clone)]
+pub struct QuotationRequestBuilder<S, P, const RECIPIENT_STOP_COUNT: usize> {
+    service: S,
+    pick_up_location: P,
+    stops: Vec<Location>,
+    is_route_optimized: bool,
+    item: Option<Item>,
+    schedule_at: Option<DateTime<Utc>>,
+}
+
+impl QuotationRequestBuilder<Unset, Unset, 0> {
+    pub fn new() -> Self {
+        QuotationRequestBuilder {
+            service: Unset,
+            pick_up_location: Unset,
+            stops: Vec::new(),
+            is_route_optimized: false,
+            item: None,
+            schedule_at: None,
+        }
+    }
+}
+
+impl Default for QuotationRequestBuilder<Unset, Unset, 0> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, P, const RECIPIENT_STOP_COUNT: usize> QuotationRequestBuilder<S, P, RECIPIENT_STOP_COUNT> {
+    pub fn with_service<T: Into<ServiceType>>(
+        self,
+        service: T,
+    ) -> QuotationRequestBuilder<T, P, RECIPIENT_STOP_COUNT> {
+        QuotationRequestBuilder {
+            service,
+            pick_up_location: self.pick_up_location,
+            stops: self.stops,
+            is_route_optimized: self.is_route_optimized,
+            item: self.item,
+            schedule_at: self.schedule_at,
+        }
+    }
+
+    pub fn with_pick_up_location(
+        self,
+        pick_up_location: Location,
+    ) -> QuotationRequestBuilder<S, Location, RECIPIENT_STOP_COUNT> {
+        QuotationRequestBuilder {
+            service: self.service,
+            pick_up_location,
+            stops: self.stops,
+            is_route_optimized: self.is_route_optimized,
+            item: self.item,
+            schedule_at: self.schedule_at,
+        }
+    }
+
+    pub fn with_stop(
+        mut self,
+        stop: Location,
+    ) -> QuotationRequestBuilder<S, P, { RECIPIENT_STOP_COUNT + 1 }>
+    where
+        [Location; RECIPIENT_STOP_COUNT + 1]: Sized,
+    {
+        self.stops.push(stop);
+
+        QuotationRequestBuilder {
+            service: self.service,
+            pick_up_location: self.pick_up_location,
+            stops: self.stops,
+            is_route_optimized: self.is_route_optimized,
+            item: self.item,
+            schedule_at: self.schedule_at,
+        }
+    }
+
+    pub fn with_route_optimized(mut self, is_route_optimized: bool) -> Self {
+        self.is_route_optimized = is_route_optimized;
+        self
+    }
+
+    pub fn with_item(mut self, item: Item) -> Self {
+        self.item = Some(item);
+        self
+    }
+
+    pub fn with_schedule_at(mut self, schedule_at: DateTime<Utc>) -> Self {
+        self.schedule_at = Some(schedule_at);
+        self
+    }
+}
+
+impl<S: Into<ServiceType>, const RECIPIENT_STOP_COUNT: usize>
+    QuotationRequestBuilder<S, Location, RECIPIENT_STOP_COUNT>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    pub fn build(self) -> QuotationRequest<RECIPIENT_STOP_COUNT, S> {
+        QuotationRequest {
+            service: self.service,
+            pick_up_location: self.pick_up_location,
+            stops: self.stops.try_into().expect(
+                "This shouldn't fail because with_stop was called RECIPIENT_STOP_COUNT times.",
+            ),
+            is_route_optimized: self.is_route_optimized,
+            item: self.item,
+            schedule_at: self.schedule_at,
+        }
+    }
+}
+
+/// Builds a [`DeliveryRequest`] for a quotation one field at a time.
+///
+/// `build` only exists once a sender and exactly one recipient per quoted stop have been set, and
+/// adding more recipients than there are stops doesn't compile.
+#[derive(Debug, Clone)]
+pub struct DeliveryRequestBuilder<
+    P,
+    const RECIPIENT_STOP_COUNT: usize,
+    const RECIPIENT_COUNT: usize,
+> where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    quoted: QuotedRequest<RECIPIENT_STOP_COUNT>,
+    sender: P,
+    recipients_info: Vec<PersonInfo>,
+    cash_on_delivery: Vec<Option<Money<'static, Currency>>>,
+    metadata: Map<String, Value>,
+    preferred_driver_ids: Vec<DriverId>,
+    payment_method: PaymentMethod,
+}
+
+impl<const RECIPIENT_STOP_COUNT: usize> DeliveryRequestBuilder<Unset, RECIPIENT_STOP_COUNT, 0>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    pub fn new(quoted: QuotedRequest<RECIPIENT_STOP_COUNT>) -> Self {
+        DeliveryRequestBuilder {
+            quoted,
+            sender: Unset,
+            recipients_info: Vec::new(),
+            cash_on_delivery: Vec::new(),
+            metadata: Map::new(),
+            preferred_driver_ids: Vec::new(),
+            payment_method: PaymentMethod::default(),
+        }
+    }
+}
+
+impl<P, const RECIPIENT_STOP_COUNT: usize, const RECIPIENT_COUNT: usize>
+    DeliveryRequestBuilder<P, RECIPIENT_STOP_COUNT, RECIPIENT_COUNT>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    pub fn with_sender(
+        self,
+        sender: PersonInfo,
+    ) -> DeliveryRequestBuilder<PersonInfo, RECIPIENT_STOP_COUNT, RECIPIENT_COUNT> {
+        DeliveryRequestBuilder {
+            quoted: self.quoted,
+            sender,
+            recipients_info: self.recipients_info,
+            cash_on_delivery: self.cash_on_delivery,
+            metadata: self.metadata,
+            preferred_driver_ids: self.preferred_driver_ids,
+            payment_method: self.payment_method,
+        }
+    }
+
+    /// Recipients are matched to the quoted stops in order.
+    pub fn with_recipient(
+        self,
+        recipient: PersonInfo,
+    ) -> DeliveryRequestBuilder<P, RECIPIENT_STOP_COUNT, { RECIPIENT_COUNT + 1 }>
+    where
+        Assert<{ RECIPIENT_COUNT < RECIPIENT_STOP_COUNT }>: IsTrue,
+        [PersonInfo; RECIPIENT_COUNT + 1]: Sized,
+    {
+        self.push_recipient(recipient, None)
+    }
+
+    pub fn with_cash_on_delivery_recipient(
+        self,
+        recipient: PersonInfo,
+        cash_on_delivery: Money<'static, Currency>,
+    ) -> DeliveryRequestBuilder<P, RECIPIENT_STOP_COUNT, { RECIPIENT_COUNT + 1 }>
+    where
+        Assert<{ RECIPIENT_COUNT < RECIPIENT_STOP_COUNT }>: IsTrue,
+        [PersonInfo; RECIPIENT_COUNT + 1]: Sized,
+    {
+        self.push_recipient(recipient, Some(cash_on_delivery))
+    }
+
+    fn push_recipient<const NEXT_RECIPIENT_COUNT: usize>(
+        mut self,
+        recipient: PersonInfo,
+        cash_on_delivery: Option<Money<'static, Currency>>,
+    ) -> DeliveryRequestBuilder<P, RECIPIENT_STOP_COUNT, NEXT_RECIPIENT_COUNT> {
+        self.recipients_info.push(recipient);
+        self.cash_on_delivery.push(cash_on_delivery);
+
+        DeliveryRequestBuilder {
+            quoted: self.quoted,
+            sender: self.sender,
+            recipients_info: self.recipients_info,
+            cash_on_delivery: self.cash_on_delivery,
+            metadata: self.metadata,
+            preferred_driver_ids: self.preferred_driver_ids,
+            payment_method: self.payment_method,
+        }
+    }
+
+    pub fn with_metadata(mut self, metadata: Map<String, Value>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn with_preferred_driver(mut self, driver_id: DriverId) -> Self {
+        self.preferred_driver_ids.push(driver_id);
+        self
+    }
+
+    pub fn with_payment_method(mut self, payment_method: PaymentMethod) -> Self {
+        self.payment_method = payment_method;
+        self
+    }
+}
+
+impl<const RECIPIENT_STOP_COUNT: usize>
+    DeliveryRequestBuilder<PersonInfo, RECIPIENT_STOP_COUNT, RECIPIENT_STOP_COUNT>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    pub fn build(self) -> DeliveryRequest<RECIPIENT_STOP_COUNT> {
+        DeliveryRequest {
+            quoted: self.quoted,
+            sender: self.sender,
+            recipients_info: self
+                .recipients_info
+                .try_into()
+                .expect("This shouldn't fail because build needs RECIPIENT_STOP_COUNT recipients."),
+            cash_on_delivery: self
+                .cash_on_delivery
+                .try_into()
+                .expect("There should be a cash on delivery entry for every recipient!"),
+            metadata: self.metadata,
+            preferred_driver_ids: self.preferred_driver_ids,
+            payment_method: self.payment_method,
+        }
+    }
+}
@@ -1,28 +1,43 @@
 use awc::{
     error::{PayloadError, SendRequestError},
-    Client as AwcClient,
+    Client as AwcClient, Connector,
 };
-use http::{Error as HttpError, Request};
+use http::{Error as HttpError, Request, Version};
 
 use async_trait::async_trait;
 use thiserror::Error as ThisError;
 
 use crate::{
-    client::{HttpClient, HttpResponse},
+    client::{FromTransportConfig, HttpClient, HttpResponse, TransportConfig},
     RequestError,
 };
 
-#[cfg(test)]
+#[cfg(all(test, feature = "market-ph"))]
 mod tests {
+    use awc::Client;
+
+    use crate::{
+        client::harness::{place_sample_order, MockSandbox, ORDER_ID},
+        DeliveryStatus, Lalamove, PhilippineMarket,
+    };
+
+    #[actix_rt::test]
+    async fn places_an_order_against_a_mock_sandbox() {
+        let sandbox = MockSandbox::start().await;
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config());
+
+        let delivery = place_sample_order(&lalamove).await;
+
+        assert_eq!(delivery.id.to_string(), ORDER_ID);
+        assert_eq!(delivery.status, DeliveryStatus::AssigningDriver);
+        assert_eq!(sandbox.server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[cfg(feature = "e2e")]
     #[actix_rt::test]
     async fn main() {
-        use crate::{
-            Config, Coordinates, DeliveryRequest, Lalamove, Location, PersonInfo,
-            PhilippineLanguages, PhilippineMarket, QuotationRequest,
-        };
-        use awc::Client;
+        use crate::{Config, PhilippineLanguages};
         use dotenvy_macro::dotenv;
-        use phonenumber::parse;
 
         let lalamove = Lalamove::<PhilippineMarket, Client>::new(
             Config::new(
@@ -33,48 +48,50 @@ mod tests {
             .unwrap(),
         );
 
-        let market_info = lalamove.market_info().await.unwrap();
-
-        let (quoted_request, _) = lalamove
-            .quote(QuotationRequest {
-                pick_up_location: Location {
-                    coordinates: Coordinates {
-                        latitude: 14.535372967557564,
-                        longitude: 120.98197538196277,
-                    },
-                    address: "SM Mall of Asia, Seaside Boulevard, 123, Pasay, Metro Manila".to_owned(),
-                },
-                service: market_info.regions[0].services[0].service.clone(),
-                stops: [Location {
-                    coordinates: Coordinates {
-                        latitude: 14.586164229973143,
-                        longitude: 121.05665251264826,
-                    },
-                    address: "SM Megamall, Doña Julia Vargas Avenue, Ortigas Center, Mandaluyong, Metro Manila".to_string(),
-                }],
-            })
-            .await
-            .unwrap();
-
-        let delivery = lalamove
-            .place_order(DeliveryRequest {
-                quoted: quoted_request,
-                sender: PersonInfo {
-                    name: "Alice".to_string(),
-                    phone_number: parse(None, "+639000001024").unwrap(),
-                },
-                recipients_info: [PersonInfo {
-                    name: "Bob".to_string(),
-                    phone_number: parse(None, "+639000000512").unwrap(),
-                }],
-            })
-            .await
-            .unwrap();
+        let delivery = place_sample_order(&lalamove).await;
 
         println!("{delivery:?}");
     }
 }
 
+#[derive(Debug, ThisError)]
+pub enum AwcTransportError {
+    #[error("awc can't send requests through a proxy.")]
+    ProxyUnsupported,
+    #[error("awc can't configure TCP keep-alive.")]
+    TcpKeepAliveUnsupported,
+}
+
+impl FromTransportConfig for AwcClient {
+    type BuildErr = AwcTransportError;
+
+    fn from_transport_config(transport: &TransportConfig) -> Result<Self, Self::BuildErr> {
+        if transport.proxy.is_some() {
+            return Err(AwcTransportError::ProxyUnsupported);
+        }
+
+        if transport.tcp_keep_alive.is_some() {
+            return Err(AwcTransportError::TcpKeepAliveUnsupported);
+        }
+
+        let mut connector = Connector::new();
+
+        if let Some(max_connections) = transport.max_connections {
+            connector = connector.limit(max_connections);
+        }
+
+        if let Some(idle_timeout) = transport.idle_timeout {
+            connector = connector.conn_keep_alive(idle_timeout);
+        }
+
+        if transport.prefer_http2 == Some(false) {
+            connector = connector.max_http_version(Version::HTTP_11);
+        }
+
+        Ok(AwcClient::builder().connector(connector).finish())
+    }
+}
+
 #[derive(Debug, ThisError)]
 pub enum AwcClientError {
     #[error(transparent)]
@@ -107,6 +124,18 @@ impl HttpClient for AwcClient {
         Ok(HttpResponse {
             bytes: Vec::from(client_response.body().await?),
             status: client_response.status(),
+            headers: client_response
+                .headers()
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
         })
     }
+
+    fn is_connect_error(error: &Self::Err) -> bool {
+        matches!(
+            error,
+            AwcClientError::SendRequestError(SendRequestError::Connect(_))
+        )
+    }
 }
@@ -0,0 +1,80 @@
+use std::{error::Error, future::Future, io::Error as IoError, str::FromStr};
+
+use tokio::{
+    runtime::{Builder, Runtime},
+    task::LocalSet,
+};
+
+use crate::{
+    client::{Config, HttpClient, Lalamove as AsyncLalamove, QuoteError, RequestError},
+    valid_recipient_stop_count, Assert, Delivery, DeliveryId, DeliveryRequest, DeliveryStatus,
+    IsTrue, Location, Market, MarketInfo, QuotationRequest, Quote, QuotedRequest,
+};
+
+pub struct Lalamove<M: Market, C: HttpClient>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    inner: AsyncLalamove<M, C>,
+    runtime: Runtime,
+}
+
+impl<M: Market, C: HttpClient> Lalamove<M, C>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    pub fn new(config: Config<M>) -> Result<Self, IoError>
+    where
+        C: Default,
+    {
+        Self::from_async(AsyncLalamove::new(config))
+    }
+
+    pub fn with_client(config: Config<M>, client: C) -> Result<Self, IoError> {
+        Self::from_async(AsyncLalamove::with_client(config, client))
+    }
+
+    pub fn from_async(inner: AsyncLalamove<M, C>) -> Result<Self, IoError> {
+        Ok(Lalamove {
+            inner,
+            runtime: Builder::new_current_thread().enable_all().build()?,
+        })
+    }
+
+    // awc spawns local tasks, so every call runs inside a LocalSet rather than directly on the
+    // runtime.
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        LocalSet::new().block_on(&self.runtime, future)
+    }
+
+    pub fn market_info(&self) -> Result<MarketInfo<M::SpecialRequests>, RequestError<C>> {
+        self.block_on(self.inner.market_info())
+    }
+
+    pub fn quote<const RECIPIENT_STOP_COUNT: usize>(
+        &self,
+        request: QuotationRequest<RECIPIENT_STOP_COUNT, M::Services>,
+    ) -> Result<(QuotedRequest<RECIPIENT_STOP_COUNT>, Quote), QuoteError<C>>
+    where
+        Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+        Assert<{ RECIPIENT_STOP_COUNT <= M::MAX_RECIPIENT_STOPS }>: IsTrue,
+        [Location; RECIPIENT_STOP_COUNT + 1]: Sized,
+    {
+        self.block_on(self.inner.quote(request))
+    }
+
+    pub fn place_order<const RECIPIENT_STOP_COUNT: usize>(
+        &self,
+        request: DeliveryRequest<RECIPIENT_STOP_COUNT>,
+    ) -> Result<Delivery, RequestError<C>>
+    where
+        Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+        Assert<{ RECIPIENT_STOP_COUNT <= M::MAX_RECIPIENT_STOPS }>: IsTrue,
+    {
+        self.block_on(self.inner.place_order(request))
+    }
+
+    pub fn delivery_status(&self, delivery: DeliveryId) -> Result<DeliveryStatus, RequestError<C>> {
+        self.block_on(self.inner.delivery_status(delivery))
+    }
+}
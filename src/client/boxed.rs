@@ -0,0 +1,96 @@
+use std::{error::Error, str::FromStr, sync::Arc};
+
+use http::Request;
+
+use async_trait::async_trait;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{HttpClient, HttpResponse, Lalamove},
+    Market, RequestError,
+};
+
+#[async_trait]
+trait ErasedHttpClient: Send + Sync {
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, BoxClientError>;
+}
+
+#[async_trait]
+impl<C: HttpClient> ErasedHttpClient for C {
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, BoxClientError> {
+        HttpClient::request(self, request)
+            .await
+            .map_err(|error| BoxClientError {
+                is_connect_error: C::is_connect_error(&error),
+                error: Box::new(error),
+            })
+    }
+}
+
+#[derive(Clone)]
+pub struct BoxClient(Arc<dyn ErasedHttpClient>);
+
+impl BoxClient {
+    pub fn new<C: HttpClient + 'static>(client: C) -> Self {
+        BoxClient(Arc::new(client))
+    }
+}
+
+#[derive(Debug, ThisError)]
+#[error("{error}")]
+pub struct BoxClientError {
+    error: Box<dyn Error + Send + Sync>,
+    is_connect_error: bool,
+}
+
+impl BoxClientError {
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        self.error.downcast_ref()
+    }
+
+    pub fn into_inner(self) -> Box<dyn Error + Send + Sync> {
+        self.error
+    }
+}
+
+impl From<BoxClientError> for RequestError<BoxClient> {
+    fn from(value: BoxClientError) -> Self {
+        RequestError::HttpClientError(value)
+    }
+}
+
+#[async_trait]
+impl HttpClient for BoxClient {
+    type Err = BoxClientError;
+
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err> {
+        self.0.request(request).await
+    }
+
+    fn is_connect_error(error: &Self::Err) -> bool {
+        error.is_connect_error
+    }
+}
+
+impl<M: Market, C: HttpClient + 'static> Lalamove<M, C>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    pub fn boxed(self) -> Lalamove<M, BoxClient> {
+        Lalamove {
+            client: Arc::new(BoxClient(self.client)),
+            config: self.config,
+            currency_converter: self.currency_converter,
+            capture: self.capture,
+            order_cache: self.order_cache,
+            rate_limit_retries: self.rate_limit_retries,
+            max_retry_after: self.max_retry_after,
+            interceptors: self.interceptors,
+            limiter: self.limiter,
+            circuit_breaker: self.circuit_breaker,
+            deadline: self.deadline,
+            #[cfg(feature = "otel")]
+            quote_spans: self.quote_spans,
+        }
+    }
+}
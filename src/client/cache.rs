@@ -0,0 +1,41 @@
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use crate::{client::Instant, DeliveryId, OrderDetails};
+
+pub(crate) struct OrderCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<DeliveryId, (Instant, OrderDetails)>>,
+}
+
+impl OrderCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        OrderCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn get(&self, delivery: &DeliveryId) -> Option<OrderDetails> {
+        let mut entries = self.entries.lock().ok()?;
+
+        self.prune(&mut entries);
+        entries.get(delivery).map(|(_, details)| details.clone())
+    }
+
+    pub(crate) fn insert(&self, details: OrderDetails) {
+        if let Ok(mut entries) = self.entries.lock() {
+            self.prune(&mut entries);
+            entries.insert(details.id.clone(), (Instant::now(), details));
+        }
+    }
+
+    fn prune(&self, entries: &mut HashMap<DeliveryId, (Instant, OrderDetails)>) {
+        entries.retain(|_, (fetched_at, _)| fetched_at.elapsed() < self.ttl);
+    }
+
+    pub(crate) fn remove(&self, delivery: &DeliveryId) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(delivery);
+        }
+    }
+}
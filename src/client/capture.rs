@@ -0,0 +1,133 @@
+use std::{
+    collections::BTreeMap,
+    fs::{File, OpenOptions},
+    io::{Result as IoResult, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+use chrono::{DateTime, Utc};
+use http::{header::AUTHORIZATION, Request};
+use serde::Serialize;
+use serde_json::{from_str, to_string, Value};
+
+use crate::client::HttpResponse;
+
+pub(crate) const REDACTED: &str = "[REDACTED]";
+const SENSITIVE_KEYS: [&str; 3] = ["phone", "phoneNumber", "signature"];
+
+pub struct Capture {
+    file: Mutex<File>,
+}
+
+impl Capture {
+    pub fn to_file(path: impl AsRef<Path>) -> IoResult<Self> {
+        Ok(Capture {
+            file: Mutex::new(OpenOptions::new().create(true).append(true).open(path)?),
+        })
+    }
+
+    pub(crate) fn record(&self, request: CapturedRequest, response: &HttpResponse) {
+        let entry = CaptureEntry {
+            captured_at: Utc::now(),
+            request,
+            response: CapturedResponse {
+                status: response.status.as_u16(),
+                body: redacted_body(&String::from_utf8_lossy(&response.bytes)),
+            },
+        };
+
+        let Ok(line) = to_string(&entry) else {
+            return;
+        };
+
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(file, "{line}");
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct CaptureEntry {
+    captured_at: DateTime<Utc>,
+    request: CapturedRequest,
+    response: CapturedResponse,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct CapturedRequest {
+    method: String,
+    uri: String,
+    headers: BTreeMap<String, String>,
+    body: Value,
+}
+
+impl CapturedRequest {
+    pub(crate) fn new(request: &Request<String>) -> Self {
+        CapturedRequest {
+            method: request.method().to_string(),
+            uri: request.uri().to_string(),
+            headers: request
+                .headers()
+                .iter()
+                .map(|(name, value)| {
+                    let value = if *name == AUTHORIZATION {
+                        REDACTED.to_string()
+                    } else {
+                        String::from_utf8_lossy(value.as_bytes()).into_owned()
+                    };
+
+                    (name.to_string(), value)
+                })
+                .collect(),
+            body: redacted_body(request.body()),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct CapturedResponse {
+    status: u16,
+    body: Value,
+}
+
+fn redacted_body(body: &str) -> Value {
+    match from_str::<Value>(body) {
+        Ok(mut json) => {
+            redact(&mut json);
+            json
+        }
+        Err(_) => Value::String(body.to_string()),
+    }
+}
+
+pub(crate) fn redacted(body: &str) -> String {
+    match from_str::<Value>(body) {
+        Ok(mut json) => {
+            redact(&mut json);
+            json.to_string()
+        }
+        Err(_) => body.to_string(),
+    }
+}
+
+fn redact(json: &mut Value) {
+    match json {
+        Value::Object(map) => {
+            // Cities and special requests have names too, so only a person's name, which always
+            // sits next to their phone number, is redacted.
+            let person = map.keys().any(|key| key.starts_with("phone"));
+
+            for (key, value) in map.iter_mut() {
+                if SENSITIVE_KEYS.contains(&key.as_str()) || (person && key == "name") {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    error::Error,
+    fs::{read_to_string, write},
+    io::{Error as IoError, Result as IoResult},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use http::{
+    header::{HeaderName, HeaderValue, AUTHORIZATION, COOKIE, SET_COOKIE},
+    HeaderMap, Method, Request, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{from_str, to_string_pretty, Error as SerdeJsonError};
+use serde_with::{serde_as, DisplayFromStr};
+
+use async_trait::async_trait;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{
+        capture::{redacted, REDACTED},
+        HttpClient, HttpResponse,
+    },
+    RequestError,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    #[serde_as(as = "DisplayFromStr")]
+    pub method: Method,
+    pub path: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+#[derive(Debug, ThisError)]
+pub enum CassetteError {
+    #[error(transparent)]
+    IoError(#[from] IoError),
+    #[error(transparent)]
+    JsonError(#[from] SerdeJsonError),
+}
+
+impl Cassette {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CassetteError> {
+        Ok(from_str(&read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CassetteError> {
+        Ok(write(path, to_string_pretty(self)?)?)
+    }
+}
+
+impl RecordedResponse {
+    fn from_response(response: &HttpResponse) -> Self {
+        RecordedResponse {
+            status: response.status.as_u16(),
+            headers: response
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    let value = if [AUTHORIZATION, COOKIE, SET_COOKIE].contains(name) {
+                        REDACTED.to_string()
+                    } else {
+                        value.to_str().ok()?.to_string()
+                    };
+
+                    Some((name.to_string(), value))
+                })
+                .collect(),
+            body: redacted(&String::from_utf8_lossy(&response.bytes)),
+        }
+    }
+
+    fn to_response(&self) -> HttpResponse {
+        let headers = self
+            .headers
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((
+                    HeaderName::from_str(name).ok()?,
+                    HeaderValue::from_str(value).ok()?,
+                ))
+            })
+            .collect::<HeaderMap>();
+
+        HttpResponse {
+            status: StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK),
+            headers,
+            bytes: self.body.clone().into_bytes(),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct RecordingClient<C> {
+    client: C,
+    recording: Arc<Recording>,
+}
+
+#[derive(Default)]
+struct Recording {
+    cassette: Mutex<Cassette>,
+    path: Option<PathBuf>,
+}
+
+impl<C> RecordingClient<C> {
+    pub fn new(client: C, path: impl Into<PathBuf>) -> Self {
+        RecordingClient {
+            client,
+            recording: Arc::new(Recording {
+                cassette: Mutex::default(),
+                path: Some(path.into()),
+            }),
+        }
+    }
+
+    pub fn cassette(&self) -> Cassette {
+        self.recording.cassette.lock().unwrap().clone()
+    }
+
+    /// Writes everything recorded so far to the cassette's path. Nothing is written on its own, so
+    /// a slow or failing disk never costs a response the server has already acted on.
+    pub fn save(&self) -> IoResult<()> {
+        let Some(path) = &self.recording.path else {
+            return Ok(());
+        };
+
+        self.cassette().save(path).map_err(|error| match error {
+            CassetteError::IoError(error) => error,
+            CassetteError::JsonError(error) => error.into(),
+        })
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum RecordingClientError<E: Error + 'static> {
+    #[error(transparent)]
+    ClientError(E),
+}
+
+impl<C> From<RecordingClientError<C::Err>> for RequestError<RecordingClient<C>>
+where
+    C: HttpClient + Sync,
+    C::Err: 'static,
+{
+    fn from(value: RecordingClientError<C::Err>) -> Self {
+        RequestError::HttpClientError(value)
+    }
+}
+
+#[cfg_attr(any(feature = "awc", feature = "wasm"), async_trait(?Send))]
+#[cfg_attr(not(any(feature = "awc", feature = "wasm")), async_trait)]
+impl<C> HttpClient for RecordingClient<C>
+where
+    C: HttpClient + Sync,
+    C::Err: 'static,
+{
+    type Err = RecordingClientError<C::Err>;
+
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err> {
+        let recorded_request = RecordedRequest {
+            method: request.method().clone(),
+            path: request.uri().path().to_string(),
+            body: redacted(request.body()),
+        };
+
+        let response = self
+            .client
+            .request(request)
+            .await
+            .map_err(RecordingClientError::ClientError)?;
+
+        self.recording
+            .cassette
+            .lock()
+            .unwrap()
+            .interactions
+            .push(Interaction {
+                request: recorded_request,
+                response: RecordedResponse::from_response(&response),
+            });
+
+        Ok(response)
+    }
+
+    fn is_connect_error(error: &Self::Err) -> bool {
+        match error {
+            RecordingClientError::ClientError(error) => C::is_connect_error(error),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct ReplayClient {
+    responses: Arc<Mutex<HashMap<(Method, String), VecDeque<RecordedResponse>>>>,
+}
+
+impl ReplayClient {
+    pub fn new(cassette: Cassette) -> Self {
+        let mut responses = HashMap::<_, VecDeque<_>>::new();
+
+        for interaction in cassette.interactions {
+            responses
+                .entry((interaction.request.method, interaction.request.path))
+                .or_default()
+                .push_back(interaction.response);
+        }
+
+        ReplayClient {
+            responses: Arc::new(Mutex::new(responses)),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CassetteError> {
+        Ok(ReplayClient::new(Cassette::load(path)?))
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum ReplayClientError {
+    #[error("The cassette doesn't have a recorded response for {method} {path}.")]
+    Unmatched { method: Method, path: String },
+}
+
+impl From<ReplayClientError> for RequestError<ReplayClient> {
+    fn from(value: ReplayClientError) -> Self {
+        RequestError::HttpClientError(value)
+    }
+}
+
+#[cfg_attr(any(feature = "awc", feature = "wasm"), async_trait(?Send))]
+#[cfg_attr(not(any(feature = "awc", feature = "wasm")), async_trait)]
+impl HttpClient for ReplayClient {
+    type Err = ReplayClientError;
+
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err> {
+        let method = request.method().clone();
+        let path = request.uri().path().to_string();
+
+        let mut responses = self.responses.lock().unwrap();
+
+        let recorded = responses
+            .get_mut(&(method.clone(), path.clone()))
+            .filter(|recorded| !recorded.is_empty())
+            .ok_or(ReplayClientError::Unmatched { method, path })?;
+
+        let response = match recorded.len() {
+            1 => recorded[0].to_response(),
+            _ => recorded.pop_front().unwrap().to_response(),
+        };
+
+        Ok(response)
+    }
+}
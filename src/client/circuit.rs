@@ -0,0 +1,71 @@
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+use crate::client::Instant;
+
+pub(crate) struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Mutex<CircuitState>,
+}
+
+enum CircuitState {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { probe_expires: Instant },
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        CircuitBreaker {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            state: Mutex::new(CircuitState::Closed { failures: 0 }),
+        }
+    }
+
+    // After the cooldown a single probe is let through. If it never reports back (e.g. its
+    // future was dropped), another probe is allowed once a further cooldown has passed.
+    pub(crate) fn try_acquire(&self) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+
+        match *state {
+            CircuitState::Closed { .. } => Ok(()),
+            CircuitState::Open { until }
+            | CircuitState::HalfOpen {
+                probe_expires: until,
+            } if now < until => Err(until - now),
+            CircuitState::Open { .. } | CircuitState::HalfOpen { .. } => {
+                *state = CircuitState::HalfOpen {
+                    probe_expires: now + self.cooldown,
+                };
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn record_success(&self) {
+        *self.state.lock().unwrap_or_else(PoisonError::into_inner) =
+            CircuitState::Closed { failures: 0 };
+    }
+
+    pub(crate) fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let failures = match *state {
+            CircuitState::Closed { failures } => failures + 1,
+            CircuitState::Open { .. } | CircuitState::HalfOpen { .. } => self.failure_threshold,
+        };
+
+        *state = if failures >= self.failure_threshold {
+            CircuitState::Open {
+                until: Instant::now() + self.cooldown,
+            }
+        } else {
+            CircuitState::Closed { failures }
+        };
+    }
+}
@@ -0,0 +1,85 @@
+use std::{error::Error, future::Future, str::FromStr};
+
+use futures::join;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{ApiEnvironment, Config, HttpClient, Lalamove},
+    Market,
+};
+
+#[derive(Clone)]
+pub struct DualEnvironment<M: Market, C: HttpClient>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    production: Lalamove<M, C>,
+    sandbox: Lalamove<M, C>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Mirrored<T> {
+    pub production: T,
+    pub sandbox: T,
+}
+
+impl<M: Market, C: HttpClient> DualEnvironment<M, C>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    pub fn new(production: Config<M>, sandbox: Config<M>) -> Result<Self, DualEnvironmentError>
+    where
+        C: Default,
+    {
+        DualEnvironment::with_clients(production, sandbox, C::default(), C::default())
+    }
+
+    pub fn with_clients(
+        production: Config<M>,
+        sandbox: Config<M>,
+        production_client: C,
+        sandbox_client: C,
+    ) -> Result<Self, DualEnvironmentError> {
+        if production.environment != ApiEnvironment::Production {
+            return Err(DualEnvironmentError::ProductionConfigNotProduction);
+        }
+
+        if sandbox.environment != ApiEnvironment::Sandbox {
+            return Err(DualEnvironmentError::SandboxConfigNotSandbox);
+        }
+
+        Ok(DualEnvironment {
+            production: Lalamove::with_client(production, production_client),
+            sandbox: Lalamove::with_client(sandbox, sandbox_client),
+        })
+    }
+
+    pub fn production(&self) -> &Lalamove<M, C> {
+        &self.production
+    }
+
+    pub fn sandbox(&self) -> &Lalamove<M, C> {
+        &self.sandbox
+    }
+
+    pub async fn mirror<'a, F, Fut>(&'a self, call: F) -> Mirrored<Fut::Output>
+    where
+        F: Fn(&'a Lalamove<M, C>) -> Fut,
+        Fut: Future,
+    {
+        let (production, sandbox) = join!(call(&self.production), call(&self.sandbox));
+
+        Mirrored {
+            production,
+            sandbox,
+        }
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum DualEnvironmentError {
+    #[error("The production config was made with sandbox credentials.")]
+    ProductionConfigNotProduction,
+    #[error("The sandbox config was made with production credentials.")]
+    SandboxConfigNotSandbox,
+}
@@ -0,0 +1,175 @@
+use phonenumber::parse;
+use serde_json::{json, Map};
+use wiremock::{
+    matchers::{body_partial_json, header_exists, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use crate::{
+    client::{Config, HttpClient, Lalamove},
+    Coordinates, Delivery, DeliveryRequest, Location, PaymentMethod, PersonInfo,
+    PhilippineLanguages, PhilippineMarket, PhilippineServices, QuotationRequest,
+};
+
+const QUOTATION_ID: &str = "1514140994227007571";
+const PICK_UP_STOP_ID: &str = "1514140995078451200";
+const DROP_OFF_STOP_ID: &str = "1514140995078451201";
+pub(crate) const ORDER_ID: &str = "107900701184";
+
+pub(crate) struct MockSandbox {
+    pub server: MockServer,
+}
+
+impl MockSandbox {
+    pub async fn start() -> Self {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v3/cities"))
+            .and(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{
+                    "locode": "PH MNL",
+                    "name": "Manila",
+                    "services": [{
+                        "key": "MOTORCYCLE",
+                        "description": "Best for small items.",
+                        "dimensions": {
+                            "length": { "value": "0.5", "unit": "m" },
+                            "width": { "value": "0.4", "unit": "m" },
+                            "height": { "value": "0.5", "unit": "m" }
+                        },
+                        "load": { "value": "20", "unit": "kg" },
+                        "specialRequests": [{
+                            "name": "THERMAL_BAG_1",
+                            "description": "Thermal bag"
+                        }]
+                    }]
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v3/quotations"))
+            .and(header_exists("authorization"))
+            .and(body_partial_json(json!({
+                "data": { "serviceType": "MOTORCYCLE", "language": "en_PH" }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "data": {
+                    "quotationId": QUOTATION_ID,
+                    "serviceType": "MOTORCYCLE",
+                    "distance": { "value": "9720", "unit": "m" },
+                    "priceBreakdown": {
+                        "base": "90",
+                        "totalExcludePriorityFee": "90",
+                        "total": "90",
+                        "currency": "PHP"
+                    },
+                    "stops": [
+                        { "stopId": PICK_UP_STOP_ID },
+                        { "stopId": DROP_OFF_STOP_ID }
+                    ],
+                    "expiresAt": "2023-10-01T07:23:43.00Z"
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v3/orders"))
+            .and(header_exists("authorization"))
+            .and(body_partial_json(json!({
+                "data": {
+                    "quotationId": QUOTATION_ID,
+                    "sender": { "stopId": PICK_UP_STOP_ID },
+                    "recipients": [{ "stopId": DROP_OFF_STOP_ID }]
+                }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "data": {
+                    "orderId": ORDER_ID,
+                    "quotationId": QUOTATION_ID,
+                    "shareLink": "https://share.sandbox.lalamove.com/?PH107900701184",
+                    "status": "ASSIGNING_DRIVER",
+                    "distance": { "value": "9720", "unit": "m" },
+                    "priceBreakdown": {
+                        "base": "90",
+                        "totalExcludePriorityFee": "90",
+                        "total": "90",
+                        "currency": "PHP"
+                    },
+                    "stops": [
+                        { "stopId": PICK_UP_STOP_ID },
+                        { "stopId": DROP_OFF_STOP_ID }
+                    ]
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        MockSandbox { server }
+    }
+
+    pub fn config(&self) -> Config<PhilippineMarket> {
+        Config::new(
+            "pk_test_harness".to_string(),
+            "sk_test_harness".to_string(),
+            PhilippineLanguages::English,
+        )
+        .unwrap()
+        .with_base_url(self.server.uri().parse().unwrap())
+    }
+}
+
+pub(crate) async fn place_sample_order<C: HttpClient>(
+    lalamove: &Lalamove<PhilippineMarket, C>,
+) -> Delivery {
+    let market_info = lalamove.market_info().await.unwrap();
+
+    let (quoted_request, _) = lalamove
+        .quote(QuotationRequest {
+            pick_up_location: Location {
+                coordinates: Coordinates {
+                    latitude: 14.535372967557564,
+                    longitude: 120.98197538196277,
+                },
+                address: "SM Mall of Asia, Seaside Boulevard, 123, Pasay, Metro Manila".to_owned(),
+            },
+            service: PhilippineServices::from(&market_info.regions[0].services[0].service),
+            stops: [Location {
+                coordinates: Coordinates {
+                    latitude: 14.586164229973143,
+                    longitude: 121.05665251264826,
+                },
+                address: "SM Megamall, Doña Julia Vargas Avenue, Ortigas Center, Mandaluyong, Metro Manila".to_string(),
+            }],
+            is_route_optimized: false,
+            item: None,
+            schedule_at: None,
+        })
+        .await
+        .unwrap();
+
+    lalamove
+        .place_order(DeliveryRequest {
+            quoted: quoted_request,
+            sender: PersonInfo {
+                name: "Alice".to_string(),
+                phone_number: parse(None, "+639000001024").unwrap(),
+                remarks: None,
+            },
+            recipients_info: [PersonInfo {
+                name: "Bob".to_string(),
+                phone_number: parse(None, "+639000000512").unwrap(),
+                remarks: None,
+            }],
+            cash_on_delivery: [None],
+            metadata: Map::new(),
+            preferred_driver_ids: Vec::new(),
+            payment_method: PaymentMethod::Wallet,
+        })
+        .await
+        .unwrap()
+}
@@ -0,0 +1,26 @@
+use std::error::Error;
+
+use http::Request;
+
+use crate::client::HttpResponse;
+
+/// Hooks that run around every request the client sends.
+///
+/// Requests pass through interceptors in the order they were added and responses pass through
+/// them in reverse. `on_request` runs after the request is signed, so changing its body or path
+/// will make Lalamove reject it.
+pub trait Interceptor: Send + Sync {
+    fn on_request(&self, _request: &mut Request<String>) {}
+
+    /// Answers the request without sending it, e.g. from a cache. The first interceptor to return
+    /// a response wins, and the interceptors before it still see that response in `on_response`.
+    fn respond(&self, _request: &Request<String>) -> Option<HttpResponse> {
+        None
+    }
+
+    fn on_response(&self, _response: &HttpResponse) {}
+
+    /// Runs when the HTTP client fails to get any response, including failures that are retried
+    /// against another base URL.
+    fn on_error(&self, _error: &dyn Error) {}
+}
@@ -0,0 +1,73 @@
+use std::io::Error as IoError;
+
+use http::Request;
+use isahc::{
+    config::Configurable,
+    error::{Error as IsahcError, ErrorKind},
+    AsyncReadResponseExt, HttpClient as IsahcHttpClient,
+};
+
+use async_trait::async_trait;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{HttpClient, HttpResponse},
+    RequestError,
+};
+
+#[derive(Clone)]
+pub struct IsahcClient(pub IsahcHttpClient);
+
+impl Default for IsahcClient {
+    fn default() -> Self {
+        IsahcClient(
+            IsahcHttpClient::builder()
+                .automatic_decompression(true)
+                .build()
+                .expect("Failed to create the isahc client!"),
+        )
+    }
+}
+
+impl From<IsahcHttpClient> for IsahcClient {
+    fn from(client: IsahcHttpClient) -> Self {
+        IsahcClient(client)
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum IsahcClientError {
+    #[error(transparent)]
+    IsahcError(#[from] IsahcError),
+    #[error(transparent)]
+    IoError(#[from] IoError),
+}
+
+impl From<IsahcClientError> for RequestError<IsahcClient> {
+    fn from(value: IsahcClientError) -> Self {
+        RequestError::HttpClientError(value)
+    }
+}
+
+#[async_trait]
+impl HttpClient for IsahcClient {
+    type Err = IsahcClientError;
+
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err> {
+        let mut response = self.0.send_async(request).await?;
+
+        Ok(HttpResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            bytes: response.bytes().await?,
+        })
+    }
+
+    fn is_connect_error(error: &Self::Err) -> bool {
+        matches!(
+            error,
+            IsahcClientError::IsahcError(error)
+                if matches!(error.kind(), ErrorKind::ConnectionFailed | ErrorKind::NameResolution)
+        )
+    }
+}
@@ -0,0 +1,196 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use http::{header::AUTHORIZATION, HeaderMap, Method, Request, StatusCode};
+use serde_json::{from_str, Value};
+
+use async_trait::async_trait;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{Config, HttpClient, HttpResponse, Lalamove},
+    Market, RequestError,
+};
+
+#[derive(Clone, Default)]
+pub struct MockClient {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    responses: HashMap<(Method, String), VecDeque<MockResponse>>,
+    requests: Vec<MockRequest>,
+}
+
+#[derive(Clone)]
+struct MockResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: Method,
+    pub path: String,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl MockRequest {
+    pub fn is_signed(&self) -> bool {
+        self.headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("hmac "))
+    }
+
+    pub fn json(&self) -> Option<Value> {
+        from_str(&self.body).ok()
+    }
+
+    pub fn data(&self) -> Option<Value> {
+        self.json()?.get_mut("data").map(Value::take)
+    }
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        MockClient::default()
+    }
+
+    pub fn respond(&self, method: Method, path: &str, status: StatusCode, body: &str) -> &Self {
+        self.respond_with(method, path, status, HeaderMap::new(), body)
+    }
+
+    pub fn respond_with(
+        &self,
+        method: Method,
+        path: &str,
+        status: StatusCode,
+        headers: HeaderMap,
+        body: &str,
+    ) -> &Self {
+        let response = MockResponse {
+            status,
+            headers,
+            body: body.to_string(),
+        };
+
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .entry((method, path.to_string()))
+            .or_default()
+            .push_back(response);
+
+        self
+    }
+
+    pub fn respond_json(
+        &self,
+        method: Method,
+        path: &str,
+        status: StatusCode,
+        body: Value,
+    ) -> &Self {
+        self.respond(method, path, status, &body.to_string())
+    }
+
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    pub fn requests_to(&self, method: &Method, path: &str) -> Vec<MockRequest> {
+        self.requests()
+            .into_iter()
+            .filter(|request| &request.method == method && request.path == path)
+            .collect()
+    }
+
+    pub fn last_request(&self) -> Option<MockRequest> {
+        self.state.lock().unwrap().requests.last().cloned()
+    }
+
+    pub fn assert_all_signed(&self) {
+        for request in self.requests() {
+            assert!(
+                request.is_signed(),
+                "{} {} wasn't signed.",
+                request.method,
+                request.path
+            );
+        }
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+
+        state.responses.clear();
+        state.requests.clear();
+    }
+}
+
+impl<M: Market> Lalamove<M, MockClient>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    pub fn with_mock(config: Config<M>, client: MockClient) -> Self {
+        Lalamove::with_client(config, client)
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum MockClientError {
+    #[error("No mock response was programmed for {method} {path}.")]
+    Unmatched { method: Method, path: String },
+}
+
+impl From<MockClientError> for RequestError<MockClient> {
+    fn from(value: MockClientError) -> Self {
+        RequestError::HttpClientError(value)
+    }
+}
+
+#[cfg_attr(any(feature = "awc", feature = "wasm"), async_trait(?Send))]
+#[cfg_attr(not(any(feature = "awc", feature = "wasm")), async_trait)]
+impl HttpClient for MockClient {
+    type Err = MockClientError;
+
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err> {
+        let method = request.method().clone();
+        let path = request.uri().path().to_string();
+
+        let mut state = self.state.lock().unwrap();
+
+        state.requests.push(MockRequest {
+            method: method.clone(),
+            path: path.clone(),
+            headers: request.headers().clone(),
+            body: request.body().clone(),
+        });
+
+        let responses = state
+            .responses
+            .get_mut(&(method.clone(), path.clone()))
+            .filter(|responses| !responses.is_empty())
+            .ok_or(MockClientError::Unmatched { method, path })?;
+
+        let response = match responses.len() {
+            1 => responses[0].clone(),
+            _ => responses.pop_front().unwrap(),
+        };
+
+        Ok(HttpResponse {
+            status: response.status,
+            headers: response.headers,
+            bytes: response.body.into_bytes(),
+        })
+    }
+}
@@ -1,95 +1,371 @@
 use std::{
+    collections::BTreeMap,
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
     iter::{once, zip},
+    pin::pin,
     str::FromStr,
     string::FromUtf8Error,
-    time::{SystemTime, UNIX_EPOCH},
+    sync::Arc,
+    time::Duration,
 };
 
-use mime::APPLICATION_JSON;
+#[cfg(feature = "otel")]
+use opentelemetry::trace::FutureExt;
+
+// `std::time::Instant::now` panics in the browser.
+#[cfg(not(feature = "wasm"))]
+pub use std::time::Instant;
+#[cfg(feature = "wasm")]
+pub use web_time::Instant;
 
 use serde::{
     de::{DeserializeOwned, Error as DeError, Unexpected},
     ser::Serialize as Serializable,
     Deserialize, Deserializer, Serialize,
 };
-use serde_json::{
-    error::{Category as DeJsonErrorCategory, Error as SerdeJsonError},
-    from_str, from_value, json, to_value, Value,
-};
-use serde_with::{serde_as, DisplayFromStr};
-
-use hex::encode;
-use hmac::{Hmac, Mac};
-use sha2::Sha256;
+use serde_json::{error::Error as SerdeJsonError, to_value, Map, Value};
+use serde_with::{serde_as, DefaultOnError, DeserializeFromStr, DisplayFromStr, SerializeDisplay};
 
 use http::{
-    header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE},
-    Method, Request, StatusCode, Uri,
+    header::{HeaderName, HeaderValue, USER_AGENT},
+    Error as HttpError, HeaderMap, Method, Request, StatusCode, Uri,
 };
 
 use thiserror::Error as ThisError;
 
+use chrono::{DateTime, Utc};
 use phonenumber::PhoneNumber;
-use rusty_money::{iso, Money, MoneyError};
+use rusty_money::{
+    iso::{self, Currency},
+    Money, MoneyError,
+};
 
 use crate::{
-    markets::Language, valid_recipient_stop_count, Assert, Delivery, DeliveryId,
-    DeliveryRequest, DeliveryStatus, Dimensions, IsTrue, Kilograms, Location, Market,
-    MarketInfo, Meters, QuotationId, QuotationRequest, Quote, QuotedRequest, Region, RegionInfo,
-    Service, ServiceType, SpecialRequest, SpecialRequestType, StopId,
+    currency::CurrencyConverter, markets::Language, money::MoneyAmount, phone::E164,
+    valid_recipient_stop_count, webhook::ApiPriceBreakdown, Assert, CancellationRejection,
+    ChangeDriverReason, ChargedAmount, Coordinates, Delivery, DeliveryId, DeliveryRequest,
+    DeliveryStatus, Dimensions, DriverId, DriverInfo, Fee, FeeKind, IsTrue, Item, Kilograms,
+    Location, Market, MarketInfo, Meters, OrderDetails, OrderEvent, OrderTimeline, PaymentMethod,
+    PodImage, QuotationId, QuotationRequest, Quote, QuotedRequest, Region, RegionInfo, Service,
+    ServiceType, SpecialRequest, SpecialRequestType, StopId, ValidationReport, WebhookEvent,
 };
 
+use async_lock::Semaphore;
 use async_trait::async_trait;
 use cfg_if::cfg_if;
+use futures::future::{join_all, select, Either};
+use futures_timer::Delay;
 
 pub struct HttpResponse {
     pub status: StatusCode,
+    pub headers: HeaderMap,
     pub bytes: Vec<u8>,
 }
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod cache;
+mod capture;
+#[cfg(feature = "test-util")]
+mod cassette;
+mod circuit;
+mod dual;
+#[cfg(all(test, feature = "market-ph", any(feature = "reqwest", feature = "awc")))]
+mod harness;
+mod interceptor;
+#[cfg(feature = "test-util")]
+mod mock;
+#[cfg(feature = "otel")]
+mod otel;
+pub mod protocol;
+#[cfg(feature = "metrics")]
+mod request_metrics;
+mod requote;
+mod sandbox;
+mod tracker;
+mod transport;
+
+use cache::OrderCache;
+pub use capture::Capture;
+use capture::CapturedRequest;
+#[cfg(feature = "test-util")]
+pub use cassette::{
+    Cassette, CassetteError, Interaction, RecordedRequest, RecordedResponse, RecordingClient,
+    RecordingClientError, ReplayClient, ReplayClientError,
+};
+use circuit::CircuitBreaker;
+pub use dual::{DualEnvironment, DualEnvironmentError, Mirrored};
+pub use interceptor::Interceptor;
+#[cfg(feature = "test-util")]
+pub use mock::{MockClient, MockClientError, MockRequest};
+use protocol::EnvelopeError;
+#[cfg(feature = "metrics")]
+pub use request_metrics::describe_metrics;
+pub use requote::{RequoteError, RequotePolicy};
+pub use sandbox::SandboxError;
+pub use tracker::{DeliveryTracker, StatusTransition, TransitionSource};
+pub use transport::{FromTransportConfig, ProxyConfig, TransportConfig};
+
 cfg_if! {
     if #[cfg(all(feature = "reqwest", feature = "awc"))] {
         compile_error!("The features [reqwest] and [awc] can't be enabled at the same time.");
-    } else if #[cfg(feature = "awc")] {
+    } else if #[cfg(all(feature = "wasm", any(feature = "reqwest", feature = "awc")))] {
+        compile_error!("The feature [wasm] can't be enabled with [reqwest] or [awc].");
+    } else if #[cfg(all(
+        feature = "surf",
+        any(feature = "reqwest", feature = "awc", feature = "wasm")
+    ))] {
+        compile_error!("The feature [surf] can't be enabled with [reqwest], [awc] or [wasm].");
+    } else if #[cfg(all(
+        feature = "isahc",
+        any(feature = "reqwest", feature = "awc", feature = "wasm", feature = "surf")
+    ))] {
+        compile_error!("The feature [isahc] can't be enabled with any other HTTP client feature.");
+    } else if #[cfg(any(feature = "awc", feature = "wasm"))] {
+        #[cfg(feature = "awc")]
         mod awc;
-
+        #[cfg(feature = "awc")]
+        pub use self::awc::AwcTransportError;
+
+        #[cfg(feature = "wasm")]
+        mod wasm;
+        #[cfg(feature = "wasm")]
+        pub use self::wasm::{FetchClient, FetchClientError};
+
+        /// The transport used to send signed requests to Lalamove.
+        ///
+        /// Requests arrive without an `Accept-Encoding` header, so implementations should
+        /// advertise the encodings they can decode and return decompressed bodies in
+        /// [`HttpResponse::bytes`].
         #[async_trait(?Send)]
-        pub trait HttpClient: Default {
+        pub trait HttpClient {
             type Err: Error + Into<RequestError<Self>>;
             async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err>;
+
+            fn is_connect_error(_error: &Self::Err) -> bool {
+                false
+            }
         }
-    } else if #[cfg(feature = "reqwest")] {
+    } else if #[cfg(any(
+        feature = "reqwest",
+        feature = "surf",
+        feature = "isahc",
+        feature = "tower",
+        feature = "test-util"
+    ))] {
+        #[cfg(feature = "reqwest")]
         mod reqwest;
-
+        #[cfg(feature = "reqwest")]
+        pub use self::reqwest::ReqwestTransportError;
+
+        mod boxed;
+        pub use boxed::{BoxClient, BoxClientError};
+
+        #[cfg(feature = "tower")]
+        mod tower;
+        #[cfg(feature = "tower")]
+        pub use self::tower::{HttpClientService, TowerClient, TowerClientError};
+
+        #[cfg(feature = "isahc")]
+        mod isahc;
+        #[cfg(feature = "isahc")]
+        pub use self::isahc::{IsahcClient, IsahcClientError};
+
+        #[cfg(feature = "surf")]
+        mod surf;
+        #[cfg(feature = "surf")]
+        pub use self::surf::{SurfClientError, SurfErrorMessage};
+
+        #[cfg(feature = "reqwest-middleware")]
+        mod reqwest_middleware;
+        #[cfg(feature = "reqwest-middleware")]
+        pub use self::reqwest_middleware::{MiddlewareClient, MiddlewareClientError};
+
+        /// The transport used to send signed requests to Lalamove.
+        ///
+        /// Requests arrive without an `Accept-Encoding` header, so implementations should
+        /// advertise the encodings they can decode and return decompressed bodies in
+        /// [`HttpResponse::bytes`]. Clients and their errors are `Send + Sync` so that the
+        /// futures returned by [`Lalamove`] can be spawned onto multi-threaded runtimes.
         #[async_trait]
-        pub trait HttpClient: Default {
-            type Err: Error + Debug + Into<RequestError<Self>>;
+        pub trait HttpClient: Send + Sync {
+            type Err: Error + Debug + Send + Sync + 'static + Into<RequestError<Self>>;
             async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err>;
+
+            fn is_connect_error(_error: &Self::Err) -> bool {
+                false
+            }
         }
     } else {
 
     }
 }
 
-#[derive(Clone)]
 pub struct Lalamove<M: Market, C: HttpClient>
 where
     <<M as Market>::Languages as FromStr>::Err: Error,
 {
-    client: C,
+    client: Arc<C>,
     config: Config<M>,
+    currency_converter: Option<Arc<dyn CurrencyConverter + Send + Sync>>,
+    capture: Option<Arc<Capture>>,
+    order_cache: Option<Arc<OrderCache>>,
+    rate_limit_retries: u32,
+    max_retry_after: Duration,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    limiter: Option<Arc<Semaphore>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    deadline: Option<Instant>,
+    #[cfg(feature = "otel")]
+    quote_spans: Arc<otel::QuoteSpans>,
+}
+
+impl<M: Market, C: HttpClient> Clone for Lalamove<M, C>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    fn clone(&self) -> Self {
+        Lalamove {
+            client: self.client.clone(),
+            config: self.config.clone(),
+            currency_converter: self.currency_converter.clone(),
+            capture: self.capture.clone(),
+            order_cache: self.order_cache.clone(),
+            rate_limit_retries: self.rate_limit_retries,
+            max_retry_after: self.max_retry_after,
+            interceptors: self.interceptors.clone(),
+            limiter: self.limiter.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            deadline: self.deadline,
+            #[cfg(feature = "otel")]
+            quote_spans: self.quote_spans.clone(),
+        }
+    }
 }
 
 impl<M: Market, C: HttpClient> Lalamove<M, C>
 where
     <<M as Market>::Languages as FromStr>::Err: Error,
 {
-    pub fn new(config: Config<M>) -> Self {
+    pub fn new(config: Config<M>) -> Self
+    where
+        C: Default,
+    {
+        Lalamove::with_client(config, C::default())
+    }
+
+    pub fn with_client(config: Config<M>, client: C) -> Self {
+        let limiter = config
+            .max_concurrent_requests
+            .filter(|&limit| limit > 0)
+            .map(|limit| Arc::new(Semaphore::new(limit)));
+
         Lalamove {
             config,
-            client: C::default(),
+            client: Arc::new(client),
+            currency_converter: None,
+            capture: None,
+            order_cache: None,
+            rate_limit_retries: 0,
+            max_retry_after: DEFAULT_MAX_RETRY_AFTER,
+            interceptors: Vec::new(),
+            limiter,
+            circuit_breaker: None,
+            deadline: None,
+            #[cfg(feature = "otel")]
+            quote_spans: Arc::default(),
+        }
+    }
+
+    pub fn with_interceptor(mut self, interceptor: impl Interceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    pub fn with_rate_limit_retries(mut self, retries: u32) -> Self {
+        self.rate_limit_retries = retries;
+        self
+    }
+
+    pub fn with_max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.max_retry_after = max_retry_after;
+        self
+    }
+
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> Self {
+        self.circuit_breaker = Some(Arc::new(CircuitBreaker::new(failure_threshold, cooldown)));
+        self
+    }
+
+    /// Returns a handle that shares this client but gives up on requests once `deadline` passes,
+    /// so it can be made for a single call.
+    pub fn with_deadline(&self, deadline: Instant) -> Self {
+        let mut lalamove = self.clone();
+        lalamove.deadline = Some(deadline);
+        lalamove
+    }
+
+    pub fn with_status_cache(mut self, ttl: Duration) -> Self {
+        self.order_cache = Some(Arc::new(OrderCache::new(ttl)));
+        self
+    }
+
+    pub fn with_capture(mut self, capture: Capture) -> Self {
+        self.capture = Some(Arc::new(capture));
+        self
+    }
+
+    /// Borrows this client's connection, limits, and caches for a request in another market, so
+    /// it's cheap enough to call for every request that needs a different `Market` header.
+    pub fn for_market<N: Market>(&self, language: N::Languages) -> Lalamove<N, C>
+    where
+        <<N as Market>::Languages as FromStr>::Err: Error,
+    {
+        Lalamove {
+            client: self.client.clone(),
+            config: self.config.for_market::<N>(language),
+            currency_converter: self.currency_converter.clone(),
+            capture: self.capture.clone(),
+            order_cache: self.order_cache.clone(),
+            rate_limit_retries: self.rate_limit_retries,
+            max_retry_after: self.max_retry_after,
+            interceptors: self.interceptors.clone(),
+            limiter: self.limiter.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            deadline: self.deadline,
+            #[cfg(feature = "otel")]
+            quote_spans: self.quote_spans.clone(),
+        }
+    }
+
+    pub fn with_currency_converter(
+        mut self,
+        converter: impl CurrencyConverter + Send + Sync + 'static,
+    ) -> Self {
+        self.currency_converter = Some(Arc::new(converter));
+        self
+    }
+
+    pub fn convert_price(
+        &self,
+        price: &Money<'static, Currency>,
+        to: &'static Currency,
+    ) -> Option<Money<'static, Currency>> {
+        if price.currency() == to {
+            return Some(price.clone());
+        }
+
+        self.currency_converter
+            .as_ref()
+            .and_then(|converter| converter.convert(price, to))
+    }
+
+    pub fn display_quote(&self, quote: &Quote, currency: &'static Currency) -> String {
+        match self.convert_price(&quote.price, currency) {
+            Some(price) => format!("{price} for {}", quote.distance),
+            None => quote.to_string(),
         }
     }
 }
@@ -102,6 +378,15 @@ pub enum QuoteError<C: HttpClient> {
     CurrencyNotFound,
     #[error(transparent)]
     MoneyError(#[from] MoneyError),
+    #[error("There weren't any pick up locations to quote from.")]
+    NoOrigins,
+    #[error("Couldn't match a stop in the optimized route to one of the requested stops.")]
+    UnmatchedStop,
+    #[error("The quote was priced in {found} instead of the market's currency, {expected}.")]
+    CurrencyMismatch {
+        expected: &'static Currency,
+        found: &'static Currency,
+    },
 }
 
 impl<C: HttpClient> Debug for QuoteError<C>
@@ -113,15 +398,58 @@ where
             Self::RequestError(e) => write!(f, "RequestError({:?})", e),
             Self::MoneyError(e) => write!(f, "MoneyError({:?})", e),
             Self::CurrencyNotFound => write!(f, "CurrencyNotFound"),
+            Self::NoOrigins => write!(f, "NoOrigins"),
+            Self::UnmatchedStop => write!(f, "UnmatchedStop"),
+            Self::CurrencyMismatch { expected, found } => write!(
+                f,
+                "CurrencyMismatch {{ expected: {:?}, found: {:?} }}",
+                expected, found
+            ),
+        }
+    }
+}
+
+#[derive(ThisError)]
+pub enum CancelError<C: HttpClient> {
+    #[error(transparent)]
+    RequestError(#[from] RequestError<C>),
+    #[error(transparent)]
+    Rejected(CancellationRejection),
+}
+
+impl<C: HttpClient> Debug for CancelError<C>
+where
+    C::Err: Error,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::RequestError(e) => write!(f, "RequestError({:?})", e),
+            Self::Rejected(e) => write!(f, "Rejected({:?})", e),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OriginPreference {
+    Cheapest,
+    Shortest,
+}
+
+#[derive(Debug)]
+pub struct BestOrigin<const RECIPIENT_STOP_COUNT: usize>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    pub origin_index: usize,
+    pub quoted: QuotedRequest<RECIPIENT_STOP_COUNT>,
+    pub quote: Quote,
+}
+
 impl<M: Market, C: HttpClient> Lalamove<M, C>
 where
     <<M as Market>::Languages as FromStr>::Err: Error,
 {
-    pub async fn market_info(&self) -> Result<MarketInfo, RequestError<C>> {
+    pub async fn market_info(&self) -> Result<MarketInfo<M::SpecialRequests>, RequestError<C>> {
         let market_info = self
             .make_request::<ApiMarketInfo>(ApiPaths::Cities, Method::GET, None::<()>)
             .await?;
@@ -148,7 +476,8 @@ where
                                 .into_iter()
                                 .map(|special_request| SpecialRequest {
                                     description: special_request.description,
-                                    special_request: special_request.name,
+                                    special_request: special_request.name.into(),
+                                    surcharge: special_request.price,
                                 })
                                 .collect(),
                             load: Kilograms(service.load.0),
@@ -182,10 +511,14 @@ where
             pub special_requests: Vec<ApiSpecialRequest>,
         }
 
+        #[serde_as]
         #[derive(Deserialize, Debug)]
         struct ApiSpecialRequest {
             pub description: String,
             pub name: SpecialRequestType,
+            #[serde_as(as = "DefaultOnError<Option<MoneyAmount>>")]
+            #[serde(default)]
+            pub price: Option<Money<'static, Currency>>,
         }
 
         #[derive(Deserialize, Debug)]
@@ -198,16 +531,31 @@ where
 
     pub async fn quote<const RECIPIENT_STOP_COUNT: usize>(
         &self,
-        request: QuotationRequest<RECIPIENT_STOP_COUNT>,
+        request: QuotationRequest<RECIPIENT_STOP_COUNT, M::Services>,
     ) -> Result<(QuotedRequest<RECIPIENT_STOP_COUNT>, Quote), QuoteError<C>>
     where
         Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+        Assert<{ RECIPIENT_STOP_COUNT <= M::MAX_RECIPIENT_STOPS }>: IsTrue,
+        [Location; RECIPIENT_STOP_COUNT + 1]: Sized,
+    {
+        self.quote_with_language(request, self.config.language.clone())
+            .await
+    }
+
+    pub async fn quote_with_language<const RECIPIENT_STOP_COUNT: usize>(
+        &self,
+        request: QuotationRequest<RECIPIENT_STOP_COUNT, M::Services>,
+        language: M::Languages,
+    ) -> Result<(QuotedRequest<RECIPIENT_STOP_COUNT>, Quote), QuoteError<C>>
+    where
+        Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+        Assert<{ RECIPIENT_STOP_COUNT <= M::MAX_RECIPIENT_STOPS }>: IsTrue,
         [Location; RECIPIENT_STOP_COUNT + 1]: Sized,
     {
         let request_clone = request.clone();
 
         let api_request = ApiQuotationRequest {
-            service_type: request_clone.service,
+            service_type: request_clone.service.into(),
             stops:  once(request_clone.pick_up_location)
                         .chain(request_clone.stops)
                         .map(|location| ApiLocation {
@@ -221,23 +569,52 @@ where
                         .try_into()
                         .expect("This shouldn't fail because the stops array's size is RECIPIENT_STOP_COUNT + 1.")
             ,
-            language: self.config.language.language_code().to_owned(),
+            is_route_optimized: request_clone.is_route_optimized,
+            item: request_clone.item,
+            schedule_at: request_clone.schedule_at,
+            language: language.language_code().to_owned(),
         };
 
-        let response = self
-            .make_request::<ApiQuote<RECIPIENT_STOP_COUNT>>(
-                ApiPaths::Quotations,
-                Method::POST,
-                Some(api_request),
-            )
-            .await?;
+        let response = self.make_request::<ApiQuote<RECIPIENT_STOP_COUNT>>(
+            ApiPaths::Quotations,
+            Method::POST,
+            Some(api_request),
+        );
 
-        let mut stops = response.stops.into_iter().map(|api_stop| api_stop.stop_id);
+        #[cfg(feature = "otel")]
+        let operation = otel::operation("lalamove.quote", Vec::new());
+        #[cfg(feature = "otel")]
+        let response = response.with_context(operation.clone());
+
+        let response = response.await?;
+
+        #[cfg(feature = "otel")]
+        self.quote_spans
+            .insert(response.quotation_id.clone(), &operation);
+
+        let mut stops = response.stops.into_iter();
         let pick_up_stop_id = stops
             .next()
-            .expect("There should have been a Stop ID for the pick up location!");
-        let stop_ids = stops
-            .collect::<Vec<_>>()
+            .expect("There should have been a Stop ID for the pick up location!")
+            .stop_id;
+        let visited_stops = stops.collect::<Vec<_>>();
+
+        let stop_order = if request.is_route_optimized {
+            original_stop_order(&request.stops, &visited_stops).ok_or(QuoteError::UnmatchedStop)?
+        } else {
+            (0..RECIPIENT_STOP_COUNT).collect()
+        };
+
+        let mut stop_ids = vec![None; RECIPIENT_STOP_COUNT];
+
+        for (visited_stop, &original_index) in zip(visited_stops, &stop_order) {
+            stop_ids[original_index] = Some(visited_stop.stop_id);
+        }
+
+        let stop_ids = stop_ids
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .expect("Every drop off location should have been matched to a Stop ID!")
             .try_into()
             .expect("There should be enough Stop IDs for the drop off locations!");
 
@@ -247,14 +624,31 @@ where
                 pick_up_stop_id,
                 stop_ids,
             },
-            Quote {
-                distance: Meters(response.distance.0),
-                price: {
-                    let currency = iso::find(&response.price_breakdown.currency)
-                        .ok_or(QuoteError::CurrencyNotFound)?;
+            {
+                let currency = iso::find(&response.price_breakdown.currency)
+                    .ok_or(QuoteError::CurrencyNotFound)?;
+
+                let expected = self
+                    .config
+                    .language
+                    .country()
+                    .map(|country| country.currency());
+
+                if let Some(expected) = expected.filter(|&expected| expected != currency) {
+                    return Err(QuoteError::CurrencyMismatch {
+                        expected,
+                        found: currency,
+                    });
+                }
 
-                    Money::from_str(&response.price_breakdown.total, currency)?
-                },
+                Quote {
+                    distance: Meters(response.distance.0),
+                    price: Money::from_str(&response.price_breakdown.total, currency)?,
+                    fees: response.price_breakdown.fees(currency)?,
+                    price_breakdown: response.price_breakdown.price_breakdown(currency)?,
+                    stop_order,
+                    expires_at: response.expires_at,
+                }
             },
         ));
 
@@ -272,6 +666,8 @@ where
             quotation_id: QuotationId,
             #[serde_as(as = "[_; RECIPIENT_STOP_COUNT + 1]")]
             stops: [ApiStopId; RECIPIENT_STOP_COUNT + 1],
+            #[serde(default)]
+            expires_at: Option<DateTime<Utc>>,
         }
 
         #[serde_as]
@@ -280,13 +676,52 @@ where
         struct ApiStopId {
             #[serde_as(as = "DisplayFromStr")]
             stop_id: StopId,
+            #[serde(default)]
+            coordinates: Option<ApiStopCoordinates>,
         }
 
+        #[serde_as]
         #[derive(Deserialize, Debug)]
-        #[serde(rename_all = "camelCase")]
-        struct ApiPriceBreakdown {
-            total: String,
-            currency: String,
+        struct ApiStopCoordinates {
+            #[serde_as(as = "DisplayFromStr")]
+            lat: f64,
+            #[serde_as(as = "DisplayFromStr")]
+            lng: f64,
+        }
+
+        fn original_stop_order(
+            stops: &[Location],
+            visited_stops: &[ApiStopId],
+        ) -> Option<Vec<usize>> {
+            // The API echoes coordinates back as rounded decimal strings, so they're matched to
+            // the nearest requested stop within roughly a metre.
+            const TOLERANCE_DEGREES: f64 = 1e-5;
+
+            let mut matched = vec![false; stops.len()];
+
+            visited_stops
+                .iter()
+                .map(|visited_stop| {
+                    let coordinates = visited_stop.coordinates.as_ref()?;
+
+                    let (index, _) = stops
+                        .iter()
+                        .enumerate()
+                        .filter(|&(index, _)| !matched[index])
+                        .map(|(index, stop)| {
+                            let offset = (stop.coordinates.latitude - coordinates.lat)
+                                .abs()
+                                .max((stop.coordinates.longitude - coordinates.lng).abs());
+
+                            (index, offset)
+                        })
+                        .filter(|&(_, offset)| offset <= TOLERANCE_DEGREES)
+                        .min_by(|(_, left), (_, right)| left.total_cmp(right))?;
+
+                    matched[index] = true;
+                    Some(index)
+                })
+                .collect()
         }
 
         #[serde_as]
@@ -315,52 +750,171 @@ where
             service_type: ServiceType,
             #[serde_as(as = "[_; RECIPIENT_STOP_COUNT + 1]")]
             stops: [ApiLocation; RECIPIENT_STOP_COUNT + 1],
+            #[serde(rename(serialize = "isRouteOptimized"))]
+            is_route_optimized: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            item: Option<Item>,
+            #[serde(
+                rename(serialize = "scheduleAt"),
+                skip_serializing_if = "Option::is_none"
+            )]
+            schedule_at: Option<DateTime<Utc>>,
             language: String,
         }
     }
 
+    pub async fn quote_best_origin<const RECIPIENT_STOP_COUNT: usize>(
+        &self,
+        service: M::Services,
+        origins: Vec<Location>,
+        stops: [Location; RECIPIENT_STOP_COUNT],
+        preference: OriginPreference,
+    ) -> Result<BestOrigin<RECIPIENT_STOP_COUNT>, QuoteError<C>>
+    where
+        Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+        Assert<{ RECIPIENT_STOP_COUNT <= M::MAX_RECIPIENT_STOPS }>: IsTrue,
+        [Location; RECIPIENT_STOP_COUNT + 1]: Sized,
+    {
+        let quotes = join_all(origins.into_iter().map(|origin| {
+            self.quote(QuotationRequest {
+                service: service.clone(),
+                pick_up_location: origin,
+                stops: stops.clone(),
+                is_route_optimized: false,
+                item: None,
+                schedule_at: None,
+            })
+        }))
+        .await;
+
+        let mut first_error = None;
+        let mut candidates = Vec::new();
+
+        for (origin_index, quote) in quotes.into_iter().enumerate() {
+            match quote {
+                Ok((quoted, quote)) => candidates.push(BestOrigin {
+                    origin_index,
+                    quoted,
+                    quote,
+                }),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        let best = candidates.into_iter().min_by(|left, right| {
+            use OriginPreference as OP;
+
+            match preference {
+                OP::Cheapest => left.quote.price.amount().cmp(right.quote.price.amount()),
+                OP::Shortest => left.quote.distance.0.total_cmp(&right.quote.distance.0),
+            }
+        });
+
+        match (best, first_error) {
+            (Some(best), _) => Ok(best),
+            (None, Some(error)) => Err(error),
+            (None, None) => Err(QuoteError::NoOrigins),
+        }
+    }
+
     pub async fn place_order<const RECIPIENT_STOP_COUNT: usize>(
         &self,
         request: DeliveryRequest<RECIPIENT_STOP_COUNT>,
     ) -> Result<Delivery, RequestError<C>>
     where
         Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+        Assert<{ RECIPIENT_STOP_COUNT <= M::MAX_RECIPIENT_STOPS }>: IsTrue,
     {
+        if let Some(country) = self.config.language.country() {
+            request.validate_phone_numbers(&country)?;
+        }
+
+        #[cfg(feature = "otel")]
+        let operation = otel::operation(
+            "lalamove.place_order",
+            self.quote_spans.links(&request.quoted.quotation_id),
+        );
+
         let request = ApiDeliveryRequest {
             quotation_id: request.quoted.quotation_id,
             sender: ApiStopInfo {
                 stop_id: request.quoted.pick_up_stop_id,
                 name: request.sender.name,
                 phone: request.sender.phone_number,
+                remarks: request.sender.remarks,
+                cash_on_delivery: None,
             },
-            recipients: zip(request.recipients_info, request.quoted.stop_ids)
-                .map(|(recipient_info, stop_id)| ApiStopInfo {
+            recipients: zip(
+                zip(request.recipients_info, request.quoted.stop_ids),
+                request.cash_on_delivery,
+            )
+            .map(
+                |((recipient_info, stop_id), cash_on_delivery)| ApiStopInfo {
                     stop_id,
                     name: recipient_info.name,
                     phone: recipient_info.phone_number,
-                })
-                .collect::<Vec<_>>()
-                .try_into()
-                .expect("There should be enough Stop IDs for the drop off locations!"),
+                    remarks: recipient_info.remarks,
+                    cash_on_delivery,
+                },
+            )
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("There should be enough Stop IDs for the drop off locations!"),
+            metadata: request.metadata,
+            preferred_driver_ids: request.preferred_driver_ids,
+            payment_method: request.payment_method,
         };
 
-        let delivery = self
-            .make_request::<ApiDelivery>(ApiPaths::Orders, Method::POST, Some(request))
-            .await?;
+        let delivery =
+            self.make_request::<ApiDelivery>(ApiPaths::Orders, Method::POST, Some(request));
+
+        #[cfg(feature = "otel")]
+        let delivery = delivery.with_context(operation);
+
+        let delivery = delivery.await?;
 
         return Ok(Delivery {
             id: delivery.order_id,
             share_link: delivery.share_link,
+            status: delivery.status,
+            distance: delivery.distance.map(|distance| Meters(distance.0)),
+            stop_ids: delivery
+                .stops
+                .into_iter()
+                .map(|stop| stop.stop_id)
+                .collect(),
+            price: delivery
+                .price_breakdown
+                .and_then(|breakdown| breakdown.charged_amount()),
         });
 
         #[serde_as]
-        #[derive(Debug, Deserialize, Serialize)]
+        #[derive(Debug, Deserialize)]
         #[serde(rename_all = "camelCase")]
-        pub struct ApiDelivery {
+        struct ApiDelivery {
             #[serde_as(as = "DisplayFromStr")]
             pub order_id: DeliveryId,
             #[serde_as(as = "DisplayFromStr")]
             pub share_link: Uri,
+            #[serde_as(as = "DisplayFromStr")]
+            pub status: DeliveryStatus,
+            #[serde(default)]
+            pub distance: Option<ApiMeters>,
+            #[serde_as(as = "DefaultOnError")]
+            #[serde(default)]
+            pub stops: Vec<ApiPlacedStop>,
+            #[serde(default)]
+            pub price_breakdown: Option<ApiPriceBreakdown>,
+        }
+
+        #[serde_as]
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ApiPlacedStop {
+            #[serde_as(as = "DisplayFromStr")]
+            pub stop_id: StopId,
         }
 
         #[serde_as]
@@ -375,6 +929,11 @@ where
             sender: ApiStopInfo,
             #[serde_as(as = "[_; RECIPIENT_STOP_COUNT]")]
             recipients: [ApiStopInfo; RECIPIENT_STOP_COUNT],
+            #[serde(skip_serializing_if = "Map::is_empty")]
+            metadata: Map<String, Value>,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            preferred_driver_ids: Vec<DriverId>,
+            payment_method: PaymentMethod,
         }
 
         #[serde_as]
@@ -384,8 +943,13 @@ where
             #[serde_as(as = "DisplayFromStr")]
             stop_id: StopId,
             name: String,
-            #[serde_as(as = "DisplayFromStr")]
+            #[serde_as(as = "E164")]
             phone: PhoneNumber,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            remarks: Option<String>,
+            #[serde_as(as = "Option<MoneyAmount>")]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            cash_on_delivery: Option<Money<'static, Currency>>,
         }
     }
 
@@ -393,12 +957,16 @@ where
         &self,
         delivery: DeliveryId,
     ) -> Result<DeliveryStatus, RequestError<C>> {
+        if let Some(cached) = self
+            .order_cache
+            .as_ref()
+            .and_then(|cache| cache.get(&delivery))
+        {
+            return Ok(cached.status);
+        }
+
         return Ok(self
-            .make_request::<ApiDeliveryDetails>(
-                ApiPaths::Order(delivery),
-                Method::GET,
-                None::<()>,
-            )
+            .make_request::<ApiDeliveryDetails>(ApiPaths::Order(delivery), Method::GET, None::<()>)
             .await?
             .status);
 
@@ -410,115 +978,667 @@ where
         }
     }
 
-    async fn make_request<'a, T: DeserializeOwned>(
+    pub async fn order_details(
         &self,
-        path: ApiPaths,
-        method: Method,
-        body: Option<impl Serializable>,
-    ) -> Result<T, RequestError<C>> {
-        let body = body.map(|body| to_value(body));
-        let body = match body {
-            Some(serialized) => Some(serialized?),
-            None => None,
-        };
-
-        let request = self.config.build_request(path, method, body);
-        let response = match self.client.request(request).await {
-            Ok(response) => response,
-            Err(error) => return Err(error.into()),
-        };
-
-        let response_string = String::from_utf8(response.bytes)?;
-        let response_json = from_str::<Value>(&response_string);
+        delivery: DeliveryId,
+    ) -> Result<OrderDetails, RequestError<C>> {
+        if let Some(cached) = self
+            .order_cache
+            .as_ref()
+            .and_then(|cache| cache.get(&delivery))
+        {
+            return Ok(cached);
+        }
 
-        return match response_json {
-            Ok(response) => {
-                use RequestError::NoData;
-                use Value as V;
-                match response {
-                    V::Object(mut map) => {
-                        let data = map.get_mut("data");
-
-                        match data {
-                            Some(data) => Ok(from_value::<T>(data.take())?),
-                            None => Err(if map.contains_key("errors") {
-                                RequestError::ApiError(ApiError::Json(V::Object(map)))
-                            } else {
-                                NoData
-                            }),
-                        }
-                    }
-                    _ => Err(NoData),
-                }
-            }
-            Err(error) => Err(match error.classify() {
-                DeJsonErrorCategory::Syntax => {
-                    RequestError::ApiError(ApiError::InvalidJson(response_string))
-                }
-                _ => RequestError::SerdeJsonError(error),
-            }),
-        };
+        self.fetch_order_details(delivery).await
     }
-}
 
-#[derive(Debug)]
-struct ApiMeters(f32);
-#[derive(Debug)]
-struct ApiKilograms(f32);
+    /// Merges the order's details with the webhooks received for it. Statuses seen while polling
+    /// can be added by building an [`OrderTimeline`] directly.
+    pub async fn order_timeline(
+        &self,
+        delivery: DeliveryId,
+        webhooks: impl IntoIterator<Item = WebhookEvent>,
+    ) -> Result<Vec<OrderEvent>, RequestError<C>> {
+        let details = self.order_details(delivery.clone()).await?;
 
-impl<'de> Deserialize<'de> for ApiKilograms {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let measurement = ApiMeasurementDeserialization::deserialize(deserializer)?;
+        let mut timeline = OrderTimeline::new(delivery);
+        timeline.record_details(&details);
 
-        if measurement.unit != "kg" {
-            return Err(DeError::invalid_value(
-                Unexpected::Str(&measurement.unit),
-                &"kg",
-            ));
+        for event in webhooks {
+            timeline.record_webhook(event);
         }
 
-        Ok(ApiKilograms(measurement.value))
+        Ok(timeline.timeline())
     }
-}
 
-impl<'de> Deserialize<'de> for ApiMeters {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let measurement = ApiMeasurementDeserialization::deserialize(deserializer)?;
+    async fn fetch_order_details(
+        &self,
+        delivery: DeliveryId,
+    ) -> Result<OrderDetails, RequestError<C>> {
+        let details = self
+            .make_request::<ApiOrderDetails>(ApiPaths::Order(delivery), Method::GET, None::<()>)
+            .await?;
 
-        if measurement.unit != "m" {
-            return Err(DeError::invalid_value(
-                Unexpected::Str(&measurement.unit),
-                &"m",
-            ));
+        let details = OrderDetails {
+            id: details.order_id,
+            status: details.status,
+            share_link: details.share_link,
+            driver_id: details.driver_id.parse().ok(),
+            created_at: details.created_at,
+            updated_at: details.updated_at,
+            driver_assigned_at: details.driver_assigned_at,
+            charged: match details.status {
+                DeliveryStatus::Completed => details
+                    .price_breakdown
+                    .and_then(|breakdown| breakdown.charged_amount()),
+                _ => None,
+            },
+            driver: details.driver.map(|driver| DriverInfo {
+                name: driver.name,
+                masked_phone: driver.phone,
+                plate_number: driver.plate_number,
+            }),
+            pod_images: details
+                .stops
+                .into_iter()
+                .filter_map(|stop| {
+                    let pod = stop.pod?;
+
+                    Some(PodImage {
+                        stop_id: stop.stop_id,
+                        url: pod.image?,
+                        delivered_at: pod.delivered_at,
+                    })
+                })
+                .collect(),
+        };
+
+        if let Some(cache) = &self.order_cache {
+            cache.insert(details.clone());
         }
 
-        Ok(ApiMeters(measurement.value))
-    }
-}
+        return Ok(details);
 
-#[serde_as]
-#[derive(Deserialize, Debug)]
-struct ApiMeasurementDeserialization {
-    unit: String,
-    #[serde_as(as = "DisplayFromStr")]
-    value: f32,
-}
+        #[serde_as]
+        #[derive(Deserialize, Debug)]
+        #[serde(rename_all = "camelCase")]
+        struct ApiOrderDetails {
+            #[serde_as(as = "DisplayFromStr")]
+            order_id: DeliveryId,
+            #[serde_as(as = "DisplayFromStr")]
+            status: DeliveryStatus,
+            #[serde_as(as = "DisplayFromStr")]
+            share_link: Uri,
+            #[serde(default)]
+            driver_id: String,
+            #[serde(default)]
+            created_at: Option<DateTime<Utc>>,
+            #[serde(default)]
+            updated_at: Option<DateTime<Utc>>,
+            #[serde(default)]
+            driver_assigned_at: Option<DateTime<Utc>>,
+            #[serde(default)]
+            price_breakdown: Option<ApiPriceBreakdown>,
+            #[serde_as(as = "DefaultOnError")]
+            #[serde(default)]
+            driver: Option<ApiDriverInfo>,
+            #[serde_as(as = "DefaultOnError")]
+            #[serde(default)]
+            stops: Vec<ApiOrderStop>,
+        }
 
-#[derive(Debug, ThisError)]
-pub enum ApiError {
-    #[error("The Lalamove API responded with the non json string '{0:?}'.")]
-    InvalidJson(String),
-    #[error(
-        "The Lalamove API responded with the json '{0:?}' which could not be deserialized."
-    )]
-    Json(Value),
-}
+        #[serde_as]
+        #[derive(Deserialize, Debug)]
+        #[serde(rename_all = "camelCase")]
+        struct ApiOrderStop {
+            #[serde_as(as = "DisplayFromStr")]
+            stop_id: StopId,
+            #[serde(rename = "POD", default)]
+            pod: Option<ApiPod>,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize, Debug)]
+        #[serde(rename_all = "camelCase")]
+        struct ApiPod {
+            #[serde_as(as = "Option<DisplayFromStr>")]
+            #[serde(default)]
+            image: Option<Uri>,
+            #[serde(default)]
+            delivered_at: Option<DateTime<Utc>>,
+        }
+
+        #[derive(Deserialize, Debug)]
+        #[serde(rename_all = "camelCase")]
+        struct ApiDriverInfo {
+            name: String,
+            phone: String,
+            plate_number: String,
+        }
+    }
+
+    pub async fn driver_location(
+        &self,
+        delivery: DeliveryId,
+        driver: DriverId,
+    ) -> Result<Coordinates, RequestError<C>> {
+        let driver = self
+            .make_request::<ApiDriver>(
+                ApiPaths::OrderDriver(delivery, driver),
+                Method::GET,
+                None::<()>,
+            )
+            .await?;
+
+        return Ok(Coordinates {
+            latitude: driver.coordinates.lat,
+            longitude: driver.coordinates.lng,
+        });
+
+        #[derive(Deserialize, Debug)]
+        struct ApiDriver {
+            coordinates: ApiDriverCoordinates,
+        }
+
+        #[serde_as]
+        #[derive(Deserialize, Debug)]
+        struct ApiDriverCoordinates {
+            #[serde_as(as = "DisplayFromStr")]
+            lat: f64,
+            #[serde_as(as = "DisplayFromStr")]
+            lng: f64,
+        }
+    }
+
+    pub async fn cancel_order(&self, delivery: DeliveryId) -> Result<(), CancelError<C>> {
+        const CANCELLATION_FORBIDDEN: &str = "ERR_CANCELLATION_FORBIDDEN";
+
+        // A cached status could say the order is still cancellable long after it was picked up.
+        let details = self.fetch_order_details(delivery.clone()).await?;
+
+        if let Some(rejection) = details.cancellation_rejection() {
+            return Err(CancelError::Rejected(rejection));
+        }
+
+        match self
+            .make_empty_request(
+                ApiPaths::Order(delivery.clone()),
+                Method::DELETE,
+                None::<()>,
+            )
+            .await
+        {
+            Ok(()) => {}
+            Err(error)
+                if error
+                    .api_error()
+                    .is_some_and(|error| error.error_ids().contains(&CANCELLATION_FORBIDDEN)) =>
+            {
+                return Err(CancelError::Rejected(CancellationRejection::Forbidden));
+            }
+            Err(error) => return Err(error.into()),
+        }
+
+        if let Some(cache) = &self.order_cache {
+            cache.remove(&delivery);
+        }
+
+        Ok(())
+    }
+
+    pub async fn change_driver(
+        &self,
+        delivery: DeliveryId,
+        driver: DriverId,
+        reason: ChangeDriverReason,
+    ) -> Result<(), RequestError<C>> {
+        self.make_empty_request(
+            ApiPaths::OrderDriver(delivery.clone(), driver),
+            Method::DELETE,
+            Some(ApiChangeDriverRequest { reason }),
+        )
+        .await?;
+
+        if let Some(cache) = &self.order_cache {
+            cache.remove(&delivery);
+        }
+
+        return Ok(());
+
+        #[derive(Serialize, Debug)]
+        struct ApiChangeDriverRequest {
+            reason: ChangeDriverReason,
+        }
+    }
+
+    pub async fn add_priority_fee(
+        &self,
+        delivery: DeliveryId,
+        amount: Money<'static, Currency>,
+    ) -> Result<(), RequestError<C>> {
+        self.make_empty_request(
+            ApiPaths::OrderPriorityFee(delivery.clone()),
+            Method::POST,
+            Some(ApiPriorityFeeRequest {
+                priority_fee: amount.amount().to_string(),
+            }),
+        )
+        .await?;
+
+        if let Some(cache) = &self.order_cache {
+            cache.remove(&delivery);
+        }
+
+        return Ok(());
+
+        #[derive(Serialize, Debug)]
+        #[serde(rename_all = "camelCase")]
+        struct ApiPriorityFeeRequest {
+            priority_fee: String,
+        }
+    }
+
+    pub async fn download_pod(&self, pod: &PodImage) -> Result<Vec<u8>, RequestError<C>> {
+        let path = &ApiPaths::PodImage(pod.url.to_string());
+        let method = &Method::GET;
+
+        // POD links are presigned, so they're sent as is instead of being signed for a base URL.
+        let response = self
+            .send_with_retries(path, method, move || async move {
+                self.dispatch(path, method, || {
+                    Request::builder()
+                        .method(Method::GET)
+                        .uri(pod.url.clone())
+                        .body(String::new())
+                })
+                .await?
+                .map_err(Into::into)
+            })
+            .await?;
+
+        if !response.status.is_success() {
+            return Err(RequestError::UnexpectedStatus(response.status));
+        }
+
+        Ok(response.bytes)
+    }
+
+    async fn make_request<'a, T: DeserializeOwned>(
+        &self,
+        path: ApiPaths,
+        method: Method,
+        body: Option<impl Serializable>,
+    ) -> Result<T, RequestError<C>> {
+        let endpoint = path.to_string();
+        let response = self.send_request(path, method.clone(), body).await?;
+        let status = response.status;
+
+        protocol::parse_response(response)
+            .map_err(|error| error.in_context(method, endpoint, status))
+    }
+
+    async fn make_empty_request(
+        &self,
+        path: ApiPaths,
+        method: Method,
+        body: Option<impl Serializable>,
+    ) -> Result<(), RequestError<C>> {
+        let endpoint = path.to_string();
+        let response = self.send_request(path, method.clone(), body).await?;
+        let status = response.status;
+
+        protocol::parse_empty_response(response)
+            .map_err(|error| error.in_context(method, endpoint, status))
+    }
+
+    async fn send_request(
+        &self,
+        path: ApiPaths,
+        method: Method,
+        body: Option<impl Serializable>,
+    ) -> Result<HttpResponse, RequestError<C>> {
+        let body = body.map(|body| to_value(body));
+        let body = match body {
+            Some(serialized) => Some(serialized?),
+            None => None,
+        };
+
+        let (path, method, body) = (&path, &method, &body);
+
+        self.send_with_retries(path, method, move || {
+            self.send_to_base_urls(path, method, body)
+        })
+        .await
+    }
+
+    async fn send_with_retries<F: Future<Output = Result<HttpResponse, RequestError<C>>>>(
+        &self,
+        path: &ApiPaths,
+        method: &Method,
+        send: impl Fn() -> F,
+    ) -> Result<HttpResponse, RequestError<C>> {
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+
+            if let Some(circuit_breaker) = &self.circuit_breaker {
+                circuit_breaker
+                    .try_acquire()
+                    .map_err(|retry_after| RequestError::CircuitOpen { retry_after })?;
+            }
+
+            let response = self.before_deadline(send()).await?;
+
+            // Being rate limited says nothing about Lalamove's health, so a 429 leaves the failure
+            // count alone. A half open probe that gets one keeps blocking until it expires.
+            if let Some(circuit_breaker) = &self.circuit_breaker {
+                match &response {
+                    Ok(response) if response.status == StatusCode::TOO_MANY_REQUESTS => {}
+                    Ok(response) if !response.status.is_server_error() => {
+                        circuit_breaker.record_success()
+                    }
+                    _ => circuit_breaker.record_failure(),
+                }
+            }
+
+            let response = response?;
+
+            if response.status != StatusCode::TOO_MANY_REQUESTS {
+                #[cfg(feature = "metrics")]
+                request_metrics::record_attempts(path, method, attempts);
+
+                return Ok(response);
+            }
+
+            let retry_after = protocol::retry_after(&response.headers);
+            let wait = retry_after.unwrap_or(DEFAULT_RETRY_AFTER);
+
+            if attempts > self.rate_limit_retries
+                || wait > self.max_retry_after
+                || matches!(self.deadline, Some(deadline) if Instant::now() + wait >= deadline)
+            {
+                #[cfg(feature = "metrics")]
+                request_metrics::record_attempts(path, method, attempts);
+
+                return Err(RequestError::RateLimited {
+                    retry_after,
+                    attempts,
+                });
+            }
+
+            Delay::new(wait).await;
+        }
+    }
+
+    async fn before_deadline<T>(
+        &self,
+        future: impl Future<Output = T>,
+    ) -> Result<T, RequestError<C>> {
+        let Some(deadline) = self.deadline else {
+            return Ok(future.await);
+        };
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+
+        if remaining.is_zero() {
+            return Err(RequestError::DeadlineExceeded);
+        }
+
+        match select(pin!(future), Delay::new(remaining)).await {
+            Either::Left((output, _)) => Ok(output),
+            Either::Right(_) => Err(RequestError::DeadlineExceeded),
+        }
+    }
+
+    async fn send_to_base_urls(
+        &self,
+        path: &ApiPaths,
+        method: &Method,
+        body: &Option<Value>,
+    ) -> Result<HttpResponse, RequestError<C>> {
+        let base_urls = self.config.base_urls();
+        let mut base_urls = base_urls.iter().peekable();
+
+        loop {
+            let base_url = base_urls
+                .next()
+                .expect("There should always be at least one base URL to try.");
+
+            let sent = self
+                .dispatch(path, method, || {
+                    protocol::build_request(
+                        &self.config,
+                        base_url,
+                        &path.to_string(),
+                        method.clone(),
+                        body.clone(),
+                        Utc::now().timestamp_millis(),
+                    )
+                })
+                .await?;
+
+            match sent {
+                Ok(response) => return Ok(response),
+                Err(error) if base_urls.peek().is_some() && C::is_connect_error(&error) => continue,
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
+
+    /// Runs one request past the limiter, interceptors, capture and telemetry. Transport errors are
+    /// handed back separately so the caller can decide whether to try another base URL.
+    #[cfg_attr(
+        not(any(feature = "metrics", feature = "otel")),
+        allow(unused_variables)
+    )]
+    async fn dispatch(
+        &self,
+        path: &ApiPaths,
+        method: &Method,
+        build: impl FnOnce() -> Result<Request<String>, HttpError>,
+    ) -> Result<Result<HttpResponse, C::Err>, RequestError<C>> {
+        // Signing happens after the permit is granted so that queued requests don't go out with
+        // stale timestamps.
+        let permit = match &self.limiter {
+            Some(limiter) => Some(limiter.acquire().await),
+            None => None,
+        };
+
+        let mut request = build()?;
+
+        for interceptor in &self.interceptors {
+            interceptor.on_request(&mut request);
+        }
+
+        let answered = self
+            .interceptors
+            .iter()
+            .enumerate()
+            .find_map(|(index, interceptor)| Some((index, interceptor.respond(&request)?)));
+
+        if let Some((index, response)) = answered {
+            for interceptor in self.interceptors[..index].iter().rev() {
+                interceptor.on_response(&response);
+            }
+
+            return Ok(Ok(response));
+        }
+
+        #[cfg(feature = "otel")]
+        let span = otel::client_span(path, method, &mut request);
+
+        let captured_request = self
+            .capture
+            .as_ref()
+            .map(|capture| (capture, CapturedRequest::new(&request)));
+
+        #[cfg(feature = "metrics")]
+        let started = Instant::now();
+
+        let result = self.client.request(request).await;
+
+        drop(permit);
+
+        #[cfg(feature = "otel")]
+        otel::end_client_span(&span, &result);
+
+        #[cfg(feature = "metrics")]
+        request_metrics::record::<C>(path, method, &result, started.elapsed());
+
+        match &result {
+            Ok(response) => {
+                for interceptor in self.interceptors.iter().rev() {
+                    interceptor.on_response(response);
+                }
+
+                if let Some((capture, captured_request)) = captured_request {
+                    capture.record(captured_request, response);
+                }
+            }
+            Err(error) => {
+                for interceptor in self.interceptors.iter().rev() {
+                    interceptor.on_error(error);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+impl EnvelopeError {
+    fn in_context<C: HttpClient>(
+        self,
+        method: Method,
+        path: String,
+        status: StatusCode,
+    ) -> RequestError<C> {
+        use EnvelopeError as EE;
+
+        match self {
+            EE::FromUtf8Error(error) => RequestError::FromUtf8Error(error),
+            EE::ApiError(error) => RequestError::ApiError {
+                method,
+                path,
+                status,
+                error,
+            },
+            EE::SerdeJsonError(error) => RequestError::SerdeJsonError(error),
+            EE::NoData => RequestError::NoData,
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+pub mod fuzzing {
+    use serde_json::Value;
+
+    pub fn parse_envelope(bytes: &[u8]) {
+        let _ = super::protocol::parse_envelope::<Value>(bytes.to_vec());
+    }
+}
+
+impl ApiPriceBreakdown {
+    fn fees(&self, currency: &'static Currency) -> Result<Vec<Fee>, MoneyError> {
+        const TOTALS: [&str; 2] = ["totalExcludePriorityFee", "totalBeforeOptimization"];
+
+        self.components
+            .iter()
+            .filter(|(key, _)| !TOTALS.contains(&key.as_str()))
+            .filter_map(|(key, value)| Some((key, value.as_str()?)))
+            .map(|(key, amount)| {
+                Money::from_str(amount, currency).map(|amount| Fee {
+                    kind: FeeKind::from(key.as_str()),
+                    amount,
+                })
+            })
+            .collect()
+    }
+
+    fn charged_amount(&self) -> Option<ChargedAmount> {
+        let currency = iso::find(&self.currency)?;
+
+        self.price_breakdown(currency).ok().map(ChargedAmount::from)
+    }
+}
+
+#[derive(Debug)]
+struct ApiMeters(f32);
+#[derive(Debug)]
+struct ApiKilograms(f32);
+
+impl<'de> Deserialize<'de> for ApiKilograms {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let measurement = ApiMeasurementDeserialization::deserialize(deserializer)?;
+
+        if measurement.unit != "kg" {
+            return Err(DeError::invalid_value(
+                Unexpected::Str(&measurement.unit),
+                &"kg",
+            ));
+        }
+
+        Ok(ApiKilograms(measurement.value))
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiMeters {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let measurement = ApiMeasurementDeserialization::deserialize(deserializer)?;
+
+        if measurement.unit != "m" {
+            return Err(DeError::invalid_value(
+                Unexpected::Str(&measurement.unit),
+                &"m",
+            ));
+        }
+
+        Ok(ApiMeters(measurement.value))
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Debug)]
+struct ApiMeasurementDeserialization {
+    unit: String,
+    #[serde_as(as = "DisplayFromStr")]
+    value: f32,
+}
+
+#[derive(Debug, ThisError)]
+pub enum ApiError {
+    #[error("The Lalamove API responded with the non json string '{0:?}'.")]
+    InvalidJson(String),
+    #[error("The Lalamove API responded with the json '{0:?}' which could not be deserialized.")]
+    Json(Value),
+}
+
+impl ApiError {
+    pub fn error_ids(&self) -> Vec<&str> {
+        match self {
+            ApiError::InvalidJson(_) => Vec::new(),
+            ApiError::Json(json) => json
+                .get("errors")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|error| error.get("id")?.as_str())
+                .collect(),
+        }
+    }
+}
 
 #[derive(ThisError)]
 pub enum RequestError<C: HttpClient>
@@ -529,12 +1649,54 @@ where
     HttpClientError(C::Err),
     #[error(transparent)]
     FromUtf8Error(#[from] FromUtf8Error),
-    #[error(transparent)]
-    ApiError(#[from] ApiError),
+    #[error("{method} {path} returned {status}: {error}")]
+    ApiError {
+        method: Method,
+        path: String,
+        status: StatusCode,
+        error: ApiError,
+    },
     #[error(transparent)]
     SerdeJsonError(#[from] SerdeJsonError),
     #[error("The json response from Lalamove didn't have the 'data' key in it.")]
     NoData,
+    #[error("The server responded with the unexpected status code {0}.")]
+    UnexpectedStatus(StatusCode),
+    #[error(transparent)]
+    InvalidRequest(#[from] ValidationReport),
+    #[error("Lalamove rate limited the request after {attempts} attempt(s).")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        attempts: u32,
+    },
+    #[error("Lalamove has been failing, so the circuit breaker is rejecting requests.")]
+    CircuitOpen { retry_after: Duration },
+    #[error("The request couldn't be completed before its deadline.")]
+    DeadlineExceeded,
+    #[error(transparent)]
+    MalformedRequest(#[from] HttpError),
+}
+
+impl<C: HttpClient> RequestError<C>
+where
+    C::Err: Error,
+{
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            RequestError::ApiError { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            RequestError::UnexpectedStatus(status) | RequestError::ApiError { status, .. } => {
+                Some(*status)
+            }
+            RequestError::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            _ => None,
+        }
+    }
 }
 
 impl<C: HttpClient> Debug for RequestError<C>
@@ -545,14 +1707,45 @@ where
         match self {
             Self::HttpClientError(e) => write!(f, "HttpClientError({:?})", e),
             Self::FromUtf8Error(e) => write!(f, "FromUtf8Error({:?})", e),
-            Self::ApiError(e) => write!(f, "ApiError({:?})", e),
+            Self::ApiError {
+                method,
+                path,
+                status,
+                error,
+            } => write!(
+                f,
+                "ApiError {{ method: {:?}, path: {:?}, status: {:?}, error: {:?} }}",
+                method, path, status, error
+            ),
             Self::SerdeJsonError(e) => write!(f, "SerdeJsonError({:?})", e),
             Self::NoData => write!(f, "NoData"),
+            Self::UnexpectedStatus(e) => write!(f, "UnexpectedStatus({:?})", e),
+            Self::InvalidRequest(e) => write!(f, "InvalidRequest({:?})", e),
+            Self::RateLimited {
+                retry_after,
+                attempts,
+            } => {
+                write!(
+                    f,
+                    "RateLimited {{ retry_after: {:?}, attempts: {:?} }}",
+                    retry_after, attempts
+                )
+            }
+            Self::CircuitOpen { retry_after } => {
+                write!(f, "CircuitOpen {{ retry_after: {:?} }}", retry_after)
+            }
+            Self::DeadlineExceeded => write!(f, "DeadlineExceeded"),
+            Self::MalformedRequest(e) => write!(f, "MalformedRequest({:?})", e),
         }
     }
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(
+    try_from = "ApiConfig<M>",
+    bound(deserialize = "M::Languages: Deserialize<'de>")
+)]
 pub struct Config<M: Market>
 where
     <<M as Market>::Languages as FromStr>::Err: Error,
@@ -561,6 +1754,27 @@ where
     pub api_secret: String,
     pub language: M::Languages,
     pub environment: ApiEnvironment,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(default)]
+    pub base_urls: Vec<Uri>,
+    #[serde(default)]
+    pub partner: Option<String>,
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub max_concurrent_requests: Option<usize>,
+}
+
+// Derived `Clone` would needlessly require the market marker type itself to be `Clone`.
+impl<M: Market> Clone for Config<M>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    fn clone(&self) -> Self {
+        self.for_market::<M>(self.language.clone())
+    }
 }
 
 impl<M: Market> Config<M>
@@ -572,8 +1786,8 @@ where
         api_secret: String,
         language: M::Languages,
     ) -> Result<Self, ConfigError> {
-        let api_key_environment = ApiEnvironment::from_str(&api_key)?;
-        let api_secret_environment = ApiEnvironment::from_str(&api_secret)?;
+        let api_key_environment = ApiEnvironment::from_key(&api_key)?;
+        let api_secret_environment = ApiEnvironment::from_key(&api_secret)?;
 
         if api_key_environment != api_secret_environment {
             return Err(ConfigError::IncompatibleKeyAndSecret);
@@ -584,49 +1798,158 @@ where
             api_secret,
             language,
             environment: api_key_environment,
+            base_urls: Vec::new(),
+            partner: None,
+            user_agent: None,
+            headers: BTreeMap::new(),
+            max_concurrent_requests: None,
         })
     }
 
-    fn build_request(
-        &self,
-        path: ApiPaths,
-        method: Method,
-        body: Option<Value>,
-    ) -> Request<String> {
-        let time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Failed to get the current system time!")
-            .as_millis();
+    pub fn for_market<N: Market>(&self, language: N::Languages) -> Config<N>
+    where
+        <<N as Market>::Languages as FromStr>::Err: Error,
+    {
+        Config {
+            api_key: self.api_key.clone(),
+            api_secret: self.api_secret.clone(),
+            language,
+            environment: self.environment.clone(),
+            base_urls: self.base_urls.clone(),
+            partner: self.partner.clone(),
+            user_agent: self.user_agent.clone(),
+            headers: self.headers.clone(),
+            max_concurrent_requests: self.max_concurrent_requests,
+        }
+    }
+
+    pub fn with_base_url(self, base_url: Uri) -> Self {
+        self.with_base_urls(vec![base_url])
+    }
 
-        let body = body.map(|value| json!({ "data": value }));
+    pub fn with_base_urls(mut self, base_urls: Vec<Uri>) -> Self {
+        self.base_urls = base_urls;
+        self
+    }
 
-        let body_str = body
-            .as_ref()
-            .map(|value| value.to_string())
-            .unwrap_or("".to_string());
+    pub fn with_partner(mut self, partner: impl Into<String>) -> Result<Self, ConfigError> {
+        let partner = partner.into();
+
+        if HeaderValue::from_str(&partner).is_err() {
+            return Err(ConfigError::InvalidHeader(
+                protocol::PARTNER_HEADER.to_string(),
+            ));
+        }
+
+        self.partner = Some(partner);
+        Ok(self)
+    }
+
+    pub fn with_max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests);
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Result<Self, ConfigError> {
+        let user_agent = user_agent.into();
+
+        if HeaderValue::from_str(&user_agent).is_err() {
+            return Err(ConfigError::InvalidHeader(USER_AGENT.to_string()));
+        }
+
+        self.user_agent = Some(user_agent);
+        Ok(self)
+    }
+
+    pub fn with_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<Self, ConfigError> {
+        let name = name.into();
+        let value = value.into();
+
+        if HeaderName::from_str(&name).is_err() || HeaderValue::from_str(&value).is_err() {
+            return Err(ConfigError::InvalidHeader(name));
+        }
+
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    fn base_urls(&self) -> Vec<String> {
+        if self.base_urls.is_empty() {
+            return vec![self.environment.base_url().to_string()];
+        }
+
+        self.base_urls
+            .iter()
+            .map(|base_url| base_url.to_string().trim_end_matches('/').to_string())
+            .collect()
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "M::Languages: Deserialize<'de>"))]
+struct ApiConfig<M: Market>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    api_key: String,
+    api_secret: String,
+    language: M::Languages,
+    #[serde(default)]
+    environment: Option<ApiEnvironment>,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(default)]
+    base_urls: Vec<Uri>,
+    #[serde(default)]
+    partner: Option<String>,
+    #[serde(default)]
+    user_agent: Option<String>,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    #[serde(default)]
+    max_concurrent_requests: Option<usize>,
+}
 
-        let path = path.to_string();
+impl<M: Market> TryFrom<ApiConfig<M>> for Config<M>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    type Error = ConfigError;
+
+    fn try_from(api_config: ApiConfig<M>) -> Result<Self, Self::Error> {
+        let mut config = Config::new(
+            api_config.api_key,
+            api_config.api_secret,
+            api_config.language,
+        )?;
+
+        if api_config
+            .environment
+            .is_some_and(|environment| environment != config.environment)
+        {
+            return Err(ConfigError::IncompatibleEnvironment);
+        }
 
-        let raw_signature = format!("{time}\r\n{method}\r\n{path}\r\n\r\n{body_str}");
+        for (name, value) in api_config.headers {
+            config = config.with_header(name, value)?;
+        }
 
-        let mut mac = Hmac::<Sha256>::new_from_slice(self.api_secret.as_bytes())
-            .expect("Failed to interpret the API SECRET as bytes!");
-        mac.update(raw_signature.as_bytes());
+        if let Some(partner) = api_config.partner {
+            config = config.with_partner(partner)?;
+        }
 
-        let signature = encode(mac.finalize().into_bytes());
+        if let Some(user_agent) = api_config.user_agent {
+            config = config.with_user_agent(user_agent)?;
+        }
 
-        let api_key = &self.api_key;
-        let application_json = APPLICATION_JSON.to_string();
+        config.base_urls = api_config.base_urls;
+        config.max_concurrent_requests = api_config.max_concurrent_requests;
 
-        Request::builder()
-            .method(method)
-            .uri(self.environment.base_url().to_string() + &path)
-            .header(ACCEPT, application_json.clone())
-            .header(CONTENT_TYPE, application_json)
-            .header(AUTHORIZATION, format!("hmac {api_key}:{time}:{signature}"))
-            .header("Market", M::country().country_code())
-            .body(body_str)
-            .expect("This should have been a valid request.")
+        Ok(config)
     }
 }
 
@@ -634,16 +1957,24 @@ where
 pub enum ConfigError {
     #[error("The API key and the API secret were not from the same environment.")]
     IncompatibleKeyAndSecret,
+    #[error("The configured environment doesn't match the environment of the API key.")]
+    IncompatibleEnvironment,
     #[error(transparent)]
     ApiEnvironmentError(#[from] ApiEnvironmentError),
+    #[error("The header '{0}' isn't a valid HTTP header.")]
+    InvalidHeader(String),
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 enum ApiPaths {
     Cities,
     Quotations,
     Orders,
     Order(DeliveryId),
+    OrderDriver(DeliveryId, DriverId),
+    OrderPriorityFee(DeliveryId),
+    SandboxOrderStatus(DeliveryId),
+    PodImage(String),
 }
 
 impl ApiPaths {
@@ -655,24 +1986,67 @@ impl ApiPaths {
             AP::Quotations => "/v3/quotations",
             AP::Orders => "/v3/orders",
             AP::Order(id) => return format!("/v3/orders/{id}"),
+            AP::OrderDriver(id, driver) => return format!("/v3/orders/{id}/drivers/{driver}"),
+            AP::OrderPriorityFee(id) => return format!("/v3/orders/{id}/priority-fee"),
+            AP::SandboxOrderStatus(id) => return format!("/v3/sandbox/orders/{id}/status"),
+            AP::PodImage(url) => url.as_str(),
         })
         .to_string()
     }
 }
 
+#[cfg(any(feature = "metrics", feature = "otel"))]
+impl ApiPaths {
+    fn endpoint(&self) -> &'static str {
+        use ApiPaths as AP;
+
+        match self {
+            AP::Cities => "/v3/cities",
+            AP::Quotations => "/v3/quotations",
+            AP::Orders => "/v3/orders",
+            AP::Order(_) => "/v3/orders/{orderId}",
+            AP::OrderDriver(_, _) => "/v3/orders/{orderId}/drivers/{driverId}",
+            AP::OrderPriorityFee(_) => "/v3/orders/{orderId}/priority-fee",
+            AP::SandboxOrderStatus(_) => "/v3/sandbox/orders/{orderId}/status",
+            AP::PodImage(_) => "pod_image",
+        }
+    }
+}
+
 impl Display for ApiPaths {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
         write!(formatter, "{}", self.path())
     }
 }
 
-#[derive(PartialEq, Eq, Debug, Serialize, Clone)]
+#[derive(PartialEq, Eq, Debug, SerializeDisplay, DeserializeFromStr, Clone)]
 pub enum ApiEnvironment {
     Sandbox,
     Production,
 }
 
 impl ApiEnvironment {
+    /// Reads the environment from the prefix of an API key or secret, like `pk_test_` or
+    /// `sk_prod_`.
+    pub fn from_key(api_key_or_api_secret: &str) -> Result<Self, ApiEnvironmentError> {
+        use ApiEnvironment as AE;
+        use ApiEnvironmentError as AEE;
+
+        let environment = api_key_or_api_secret
+            .chars()
+            .skip(3)
+            .take(4)
+            .collect::<String>();
+
+        if environment.starts_with("test") {
+            Ok(AE::Sandbox)
+        } else if environment.starts_with("prod") {
+            Ok(AE::Production)
+        } else {
+            Err(AEE::InvalidApiKeyOrApiSecret)
+        }
+    }
+
     const fn base_url(&self) -> &'static str {
         use ApiEnvironment as AE;
 
@@ -683,25 +2057,33 @@ impl ApiEnvironment {
     }
 }
 
+impl Display for ApiEnvironment {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        use ApiEnvironment as AE;
+
+        write!(
+            formatter,
+            "{}",
+            match self {
+                AE::Sandbox => "sandbox",
+                AE::Production => "production",
+            }
+        )
+    }
+}
+
 impl FromStr for ApiEnvironment {
     type Err = ApiEnvironmentError;
 
-    fn from_str(api_key_or_api_secret: &str) -> Result<Self, Self::Err> {
-        let environment = api_key_or_api_secret
-            .chars()
-            .skip(3)
-            .take(4)
-            .collect::<String>();
-
+    fn from_str(environment: &str) -> Result<Self, Self::Err> {
         use ApiEnvironment as AE;
-        use ApiEnvironmentError as AEE;
 
-        if environment.starts_with("test") {
-            Ok(AE::Sandbox)
-        } else if environment.starts_with("prod") {
-            Ok(AE::Production)
-        } else {
-            Err(AEE::InvalidApiKeyOrApiSecret)
+        match &*environment.trim().to_lowercase() {
+            "sandbox" => Ok(AE::Sandbox),
+            "production" => Ok(AE::Production),
+            _ => Err(ApiEnvironmentError::UnknownEnvironment(
+                environment.to_string(),
+            )),
         }
     }
 }
@@ -710,4 +2092,6 @@ impl FromStr for ApiEnvironment {
 pub enum ApiEnvironmentError {
     #[error("The environment of the API key or API secret couldn't be parsed correctly.")]
     InvalidApiKeyOrApiSecret,
+    #[error("'{0}' isn't an environment, expected 'sandbox' or 'production'.")]
+    UnknownEnvironment(String),
 }
@@ -0,0 +1,114 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr, sync::Mutex};
+
+use http::{
+    header::{HeaderName, HeaderValue},
+    HeaderMap, Method, Request,
+};
+use opentelemetry::{
+    global,
+    propagation::Injector,
+    trace::{Link, SpanContext, SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+
+use crate::{
+    client::{ApiPaths, HttpResponse},
+    QuotationId,
+};
+
+const TRACER: &str = "lalamove";
+
+// Quotes that never turn into orders would otherwise pile up forever.
+const MAX_QUOTE_SPANS: usize = 1024;
+
+#[derive(Default)]
+pub(crate) struct QuoteSpans(Mutex<HashMap<QuotationId, SpanContext>>);
+
+impl QuoteSpans {
+    pub fn insert(&self, quotation_id: QuotationId, operation: &Context) {
+        let mut spans = self.0.lock().unwrap();
+
+        if spans.len() >= MAX_QUOTE_SPANS {
+            spans.clear();
+        }
+
+        spans.insert(quotation_id, operation.span().span_context().clone());
+    }
+
+    pub fn links(&self, quotation_id: &QuotationId) -> Vec<Link> {
+        self.0
+            .lock()
+            .unwrap()
+            .remove(quotation_id)
+            .map(|span_context| Link::new(span_context, Vec::new()))
+            .into_iter()
+            .collect()
+    }
+}
+
+pub(crate) fn operation(name: &'static str, links: Vec<Link>) -> Context {
+    let tracer = global::tracer(TRACER);
+
+    let span = tracer
+        .span_builder(name)
+        .with_kind(SpanKind::Internal)
+        .with_links(links)
+        .start(&tracer);
+
+    Context::current_with_span(span)
+}
+
+pub(crate) fn client_span(
+    path: &ApiPaths,
+    method: &Method,
+    request: &mut Request<String>,
+) -> Context {
+    let tracer = global::tracer(TRACER);
+
+    let span = tracer
+        .span_builder(format!("{method} {}", path.endpoint()))
+        .with_kind(SpanKind::Client)
+        .with_attributes(vec![
+            KeyValue::new("http.method", method.to_string()),
+            KeyValue::new("http.url", request.uri().to_string()),
+        ])
+        .start(&tracer);
+
+    let context = Context::current_with_span(span);
+
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(request.headers_mut()))
+    });
+
+    context
+}
+
+pub(crate) fn end_client_span<E: Display>(context: &Context, result: &Result<HttpResponse, E>) {
+    let span = context.span();
+
+    match result {
+        Ok(response) => {
+            span.set_attribute(KeyValue::new(
+                "http.status_code",
+                i64::from(response.status.as_u16()),
+            ));
+
+            if response.status.is_client_error() || response.status.is_server_error() {
+                span.set_status(Status::error(response.status.to_string()));
+            }
+        }
+        Err(error) => span.set_status(Status::error(error.to_string())),
+    }
+
+    span.end();
+}
+
+struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+impl Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(name), Ok(value)) = (HeaderName::from_str(key), HeaderValue::from_str(&value)) {
+            self.0.insert(name, value);
+        }
+    }
+}
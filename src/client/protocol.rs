@@ -0,0 +1,158 @@
+use std::{error::Error, str::FromStr, string::FromUtf8Error, time::Duration};
+
+use chrono::{DateTime, Utc};
+use http::{
+    header::{
+        HeaderName, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER, USER_AGENT,
+    },
+    Error as HttpError, HeaderMap, Method, Request,
+};
+use mime::APPLICATION_JSON;
+use serde::de::DeserializeOwned;
+use serde_json::{
+    error::{Category as DeJsonErrorCategory, Error as SerdeJsonError},
+    from_str, from_value, json, Value,
+};
+use thiserror::Error as ThisError;
+
+pub use crate::signing::sign;
+
+use crate::{
+    client::{ApiError, Config, HttpResponse},
+    markets::Language,
+    Market,
+};
+
+pub(crate) const PARTNER_HEADER: &str = "X-LLM-Partner";
+
+pub fn build_request<M: Market>(
+    config: &Config<M>,
+    base_url: &str,
+    path: &str,
+    method: Method,
+    body: Option<Value>,
+    time: i64,
+) -> Result<Request<String>, HttpError>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    let body = body.map(|value| json!({ "data": value }));
+
+    let body_str = body
+        .as_ref()
+        .map(|value| value.to_string())
+        .unwrap_or("".to_string());
+
+    let signature = sign(&config.api_secret, time, &method, path, &body_str);
+
+    let api_key = &config.api_key;
+    let application_json = APPLICATION_JSON.to_string();
+
+    let request = Request::builder()
+        .method(method)
+        .uri(base_url.to_string() + path)
+        .header(ACCEPT, application_json.clone())
+        .header(CONTENT_TYPE, application_json)
+        .header(AUTHORIZATION, format!("hmac {api_key}:{time}:{signature}"))
+        .header("Market", config.language.country_code());
+
+    let request = match &config.partner {
+        Some(partner) => request.header(PARTNER_HEADER, partner),
+        None => request,
+    };
+
+    let mut request = match &config.user_agent {
+        Some(user_agent) => request.header(USER_AGENT, user_agent),
+        None => request,
+    };
+
+    if let Some(headers) = request.headers_mut() {
+        for (name, value) in &config.headers {
+            let (Ok(name), Ok(value)) = (HeaderName::from_str(name), HeaderValue::from_str(value))
+            else {
+                continue;
+            };
+
+            if !headers.contains_key(&name) {
+                headers.insert(name, value);
+            }
+        }
+    }
+
+    request.body(body_str)
+}
+
+pub fn parse_response<T: DeserializeOwned>(response: HttpResponse) -> Result<T, EnvelopeError> {
+    parse_envelope(response.bytes)
+}
+
+pub fn parse_empty_response(response: HttpResponse) -> Result<(), EnvelopeError> {
+    if response.status.is_success() && response.bytes.iter().all(u8::is_ascii_whitespace) {
+        return Ok(());
+    }
+
+    parse_envelope::<Value>(response.bytes)?;
+
+    Ok(())
+}
+
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let retry_after = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = retry_after.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let retry_at = DateTime::parse_from_rfc2822(retry_after).ok()?;
+
+    Some(
+        (retry_at.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+#[derive(Debug, ThisError)]
+pub enum EnvelopeError {
+    #[error(transparent)]
+    FromUtf8Error(#[from] FromUtf8Error),
+    #[error(transparent)]
+    ApiError(#[from] ApiError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] SerdeJsonError),
+    #[error("The json response from Lalamove didn't have the 'data' key in it.")]
+    NoData,
+}
+
+pub(crate) fn parse_envelope<T: DeserializeOwned>(bytes: Vec<u8>) -> Result<T, EnvelopeError> {
+    let response_string = String::from_utf8(bytes)?;
+    let response_json = from_str::<Value>(&response_string);
+
+    match response_json {
+        Ok(response) => {
+            use EnvelopeError::NoData;
+            use Value as V;
+            match response {
+                V::Object(mut map) => {
+                    let data = map.get_mut("data");
+
+                    match data {
+                        Some(data) => Ok(from_value::<T>(data.take())?),
+                        None => Err(if map.contains_key("errors") {
+                            EnvelopeError::ApiError(ApiError::Json(V::Object(map)))
+                        } else {
+                            NoData
+                        }),
+                    }
+                }
+                _ => Err(NoData),
+            }
+        }
+        Err(error) => Err(match error.classify() {
+            DeJsonErrorCategory::Syntax => {
+                EnvelopeError::ApiError(ApiError::InvalidJson(response_string))
+            }
+            _ => EnvelopeError::SerdeJsonError(error),
+        }),
+    }
+}
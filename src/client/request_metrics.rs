@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use http::{Method, StatusCode};
+use metrics::{counter, describe_counter, describe_histogram, histogram, Unit};
+
+use crate::client::{ApiPaths, HttpClient, HttpResponse};
+
+const REQUESTS: &str = "lalamove_requests_total";
+const ERRORS: &str = "lalamove_errors_total";
+const DURATION: &str = "lalamove_request_duration_seconds";
+const ATTEMPTS: &str = "lalamove_request_attempts";
+
+pub fn describe_metrics() {
+    describe_counter!(
+        REQUESTS,
+        "Requests sent to the Lalamove API, by status and outcome."
+    );
+    describe_counter!(
+        ERRORS,
+        "Failed requests to the Lalamove API by error class."
+    );
+    describe_histogram!(
+        DURATION,
+        Unit::Seconds,
+        "Time taken by requests to the Lalamove API."
+    );
+    describe_histogram!(
+        ATTEMPTS,
+        Unit::Count,
+        "Attempts made per request, including rate limit retries."
+    );
+}
+
+pub(crate) fn record<C: HttpClient>(
+    path: &ApiPaths,
+    method: &Method,
+    result: &Result<HttpResponse, C::Err>,
+    elapsed: Duration,
+) {
+    let endpoint = path.endpoint();
+    let method = method.to_string();
+
+    histogram!(DURATION, elapsed.as_secs_f64(), "method" => method.clone(), "endpoint" => endpoint);
+
+    let (status, error_class) = match result {
+        Ok(response) => (
+            response.status.as_str().to_string(),
+            error_class(response.status),
+        ),
+        Err(error) if C::is_connect_error(error) => ("none".to_string(), Some("connect")),
+        Err(_) => ("none".to_string(), Some("transport")),
+    };
+
+    counter!(
+        REQUESTS,
+        1,
+        "method" => method.clone(),
+        "endpoint" => endpoint,
+        "status" => status,
+        "outcome" => if error_class.is_some() { "error" } else { "success" }
+    );
+
+    if let Some(class) = error_class {
+        counter!(ERRORS, 1, "method" => method, "endpoint" => endpoint, "class" => class);
+    }
+}
+
+pub(crate) fn record_attempts(path: &ApiPaths, method: &Method, attempts: u32) {
+    histogram!(
+        ATTEMPTS,
+        f64::from(attempts),
+        "method" => method.to_string(),
+        "endpoint" => path.endpoint()
+    );
+}
+
+fn error_class(status: StatusCode) -> Option<&'static str> {
+    match status {
+        StatusCode::TOO_MANY_REQUESTS => Some("rate_limited"),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Some("auth"),
+        status if status.is_client_error() => Some("client"),
+        status if status.is_server_error() => Some("server"),
+        _ => None,
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use rust_decimal::Decimal;
+use rusty_money::{iso::Currency, Money};
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{HttpClient, Lalamove, QuoteError, RequestError},
+    valid_recipient_stop_count, Assert, Delivery, DeliveryRequest, IsTrue, Location, Market,
+    QuotationRequest, Quote,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequotePolicy {
+    pub max_increase: Decimal,
+}
+
+impl RequotePolicy {
+    pub fn new(max_increase: Decimal) -> Self {
+        RequotePolicy { max_increase }
+    }
+
+    fn accepts(
+        &self,
+        previous: &Money<'static, Currency>,
+        current: &Money<'static, Currency>,
+    ) -> bool {
+        previous.currency() == current.currency()
+            && *current.amount() <= *previous.amount() * (Decimal::ONE + self.max_increase)
+    }
+}
+
+impl<M: Market, C: HttpClient> Lalamove<M, C>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    pub async fn place_order_with_requote<const RECIPIENT_STOP_COUNT: usize>(
+        &self,
+        quotation: QuotationRequest<RECIPIENT_STOP_COUNT, M::Services>,
+        quote: &Quote,
+        request: DeliveryRequest<RECIPIENT_STOP_COUNT>,
+        policy: RequotePolicy,
+    ) -> Result<Delivery, RequoteError<C>>
+    where
+        Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+        Assert<{ RECIPIENT_STOP_COUNT <= M::MAX_RECIPIENT_STOPS }>: IsTrue,
+        [Location; RECIPIENT_STOP_COUNT + 1]: Sized,
+    {
+        const QUOTATION_EXPIRED: &str = "ERR_QUOTATION_EXPIRED";
+
+        match self.place_order(request.clone()).await {
+            Err(error)
+                if error
+                    .api_error()
+                    .is_some_and(|error| error.error_ids().contains(&QUOTATION_EXPIRED)) => {}
+            placed => return Ok(placed?),
+        }
+
+        let (quoted, requote) = self.quote(quotation).await?;
+
+        if !policy.accepts(&quote.price, &requote.price) {
+            return Err(RequoteError::PriceChanged {
+                previous: quote.price.clone(),
+                current: requote.price,
+            });
+        }
+
+        Ok(self
+            .place_order(DeliveryRequest { quoted, ..request })
+            .await?)
+    }
+}
+
+#[derive(ThisError)]
+pub enum RequoteError<C: HttpClient> {
+    #[error(transparent)]
+    RequestError(#[from] RequestError<C>),
+    #[error(transparent)]
+    QuoteError(#[from] QuoteError<C>),
+    #[error("The new quotation's price of {current} is outside of the tolerance for the original price of {previous}.")]
+    PriceChanged {
+        previous: Money<'static, Currency>,
+        current: Money<'static, Currency>,
+    },
+}
+
+impl<C: HttpClient> Debug for RequoteError<C>
+where
+    C::Err: Error,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::RequestError(e) => write!(f, "RequestError({:?})", e),
+            Self::QuoteError(e) => write!(f, "QuoteError({:?})", e),
+            Self::PriceChanged { previous, current } => write!(
+                f,
+                "PriceChanged {{ previous: {:?}, current: {:?} }}",
+                previous, current
+            ),
+        }
+    }
+}
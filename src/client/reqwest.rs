@@ -1,78 +1,632 @@
 use http::{Error as HttpError, Request};
-use reqwest::{Client as ReqwestClient, Error as ReqwestError};
+use reqwest::{Client as ReqwestClient, Error as ReqwestError, NoProxy, Proxy};
 
 use async_trait::async_trait;
 use thiserror::Error as ThisError;
 
 use crate::{
-    client::{HttpClient, HttpResponse},
+    client::{FromTransportConfig, HttpClient, HttpResponse, TransportConfig},
     RequestError,
 };
 
-#[cfg(test)]
+#[cfg(all(test, feature = "market-ph"))]
 mod tests {
+    use std::time::{Duration, Instant};
+
+    use futures::{
+        future::{select, Either},
+        stream::{unfold, StreamExt},
+    };
+    use futures_timer::Delay;
+    use http::{Method, StatusCode};
+    use reqwest::Client;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{header, path},
+        Mock, ResponseTemplate,
+    };
+
+    use crate::{
+        client::harness::{place_sample_order, MockSandbox, ORDER_ID},
+        Coordinates, DeliveryStatus, DeliveryTracker, DynamicLocale, DynamicMarket, HttpResponse,
+        Interceptor, Lalamove, Location, PhilippineMarket, PhilippineServices, QuotationRequest,
+        RequestError, WebhookEvent,
+    };
 
     #[tokio::test]
-    async fn main() {
-        use crate::{
-            Config, Coordinates, DeliveryRequest, Lalamove, Location, PersonInfo,
-            PhilippineLanguages, PhilippineMarket, QuotationRequest,
-        };
-        use dotenvy_macro::dotenv;
-        use phonenumber::parse;
-        use reqwest::Client;
+    async fn places_an_order_against_a_mock_sandbox() {
+        let sandbox = MockSandbox::start().await;
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config());
 
-        let lalamove = Lalamove::<PhilippineMarket, Client>::new(
-            Config::new(
-                dotenv!("LALAMOVE_API_KEY").to_string(),
-                dotenv!("LALAMOVE_API_SECRET").to_string(),
-                PhilippineLanguages::English,
+        let delivery = place_sample_order(&lalamove).await;
+
+        assert_eq!(delivery.id.to_string(), ORDER_ID);
+        assert_eq!(delivery.status, DeliveryStatus::AssigningDriver);
+        assert_eq!(sandbox.server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn lists_typed_special_requests() {
+        use crate::PhilippineSpecialRequests;
+
+        let sandbox = MockSandbox::start().await;
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config());
+
+        let market_info = lalamove.market_info().await.unwrap();
+        let service = &market_info.regions[0].services[0];
+
+        assert_eq!(
+            PhilippineServices::from(&service.service).special_requests(),
+            service
+                .special_requests
+                .iter()
+                .map(|special_request| special_request.special_request.clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            service.special_requests[0].special_request,
+            PhilippineSpecialRequests::ThermalBag1
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "test-util")]
+    async fn records_redacted_cassettes_that_replay() {
+        use crate::{RecordingClient, ReplayClient};
+
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path(format!("/v3/orders/{ORDER_ID}/drivers/80557")))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("set-cookie", "session=secret")
+                    .set_body_json(json!({
+                        "data": {
+                            "driverId": "80557",
+                            "name": "Carol",
+                            "phone": "+639000000256",
+                            "plateNumber": "VP9946964",
+                            "coordinates": { "lat": "14.5", "lng": "121.0" }
+                        }
+                    })),
             )
-            .unwrap(),
+            .mount(&sandbox.server)
+            .await;
+
+        let cassette_path = std::env::temp_dir().join("lalamove-rs-redacted-cassette.json");
+        let recording = RecordingClient::new(Client::new(), &cassette_path);
+        let lalamove = Lalamove::with_client(sandbox.config(), recording.clone());
+
+        place_sample_order(&lalamove).await;
+        let recorded = lalamove
+            .driver_location(ORDER_ID.parse().unwrap(), "80557".parse().unwrap())
+            .await
+            .unwrap();
+
+        let cassette = serde_json::to_string(&recording.cassette()).unwrap();
+
+        for secret in [
+            "Alice",
+            "+639000001024",
+            "Carol",
+            "+639000000256",
+            "session=secret",
+        ] {
+            assert!(!cassette.contains(secret), "{secret} was recorded");
+        }
+        assert!(cassette.contains("THERMAL_BAG_1"));
+
+        recording.save().unwrap();
+
+        let replay = Lalamove::with_client(
+            sandbox.config(),
+            ReplayClient::load(&cassette_path).unwrap(),
+        );
+        let replayed = replay
+            .driver_location(ORDER_ID.parse().unwrap(), "80557".parse().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(replayed.latitude, recorded.latitude);
+        assert_eq!(replayed.longitude, recorded.longitude);
+    }
+
+    #[tokio::test]
+    async fn places_an_order_from_a_spawned_task() {
+        fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+        assert_send_sync::<RequestError<Client>>();
+
+        let sandbox = MockSandbox::start().await;
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config());
+
+        let delivery = tokio::spawn(async move { place_sample_order(&lalamove).await })
+            .await
+            .unwrap();
+
+        assert_eq!(delivery.id.to_string(), ORDER_ID);
+    }
+
+    #[tokio::test]
+    async fn gives_up_at_the_deadline() {
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path("/v3/cities"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "data": [] }))
+                    .set_delay(Duration::from_secs(5)),
+            )
+            .with_priority(1)
+            .mount(&sandbox.server)
+            .await;
+
+        let started = Instant::now();
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config())
+            .with_deadline(started + Duration::from_millis(200));
+
+        let error = lalamove.market_info().await.unwrap_err();
+
+        assert!(matches!(error, RequestError::DeadlineExceeded));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn keeps_polling_while_unrelated_webhooks_arrive() {
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path(format!("/v3/orders/{ORDER_ID}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "status": "ASSIGNING_DRIVER" }
+            })))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&sandbox.server)
+            .await;
+
+        Mock::given(path(format!("/v3/orders/{ORDER_ID}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "status": "ON_GOING" }
+            })))
+            .with_priority(2)
+            .mount(&sandbox.server)
+            .await;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config());
+
+        let unrelated = Box::pin(unfold((), |_| async {
+            Delay::new(Duration::from_millis(5)).await;
+            Some((WebhookEvent::Other("ORDER_ETA_CHANGED".to_string()), ()))
+        }));
+
+        let interval = Duration::from_millis(50);
+        let mut transitions = Box::pin(
+            DeliveryTracker::new()
+                .with_intervals(interval, interval)
+                .track(&lalamove, ORDER_ID.parse().unwrap(), unrelated),
         );
 
+        let statuses = async {
+            let first = transitions.next().await.unwrap().unwrap();
+            let second = transitions.next().await.unwrap().unwrap();
+
+            (first.to, second.to)
+        };
+
+        let Either::Left((statuses, _)) =
+            select(Box::pin(statuses), Delay::new(Duration::from_secs(5))).await
+        else {
+            panic!("The tracker stopped polling while webhooks kept arriving.");
+        };
+
+        assert_eq!(
+            statuses,
+            (DeliveryStatus::AssigningDriver, DeliveryStatus::Ongoing)
+        );
+    }
+
+    #[tokio::test]
+    async fn matches_optimized_stops_with_rounded_coordinates() {
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path("/v3/quotations"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "data": {
+                    "quotationId": "1514140994227007571",
+                    "distance": { "value": "12000", "unit": "m" },
+                    "priceBreakdown": { "base": "120", "total": "120", "currency": "PHP" },
+                    "stops": [
+                        { "stopId": "1", "coordinates": { "lat": "14.535373", "lng": "120.981975" } },
+                        { "stopId": "3", "coordinates": { "lat": "14.554729", "lng": "121.024445" } },
+                        { "stopId": "2", "coordinates": { "lat": "14.586164", "lng": "121.056653" } }
+                    ]
+                }
+            })))
+            .with_priority(1)
+            .mount(&sandbox.server)
+            .await;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config());
         let market_info = lalamove.market_info().await.unwrap();
 
-        let (quoted_request, _) = lalamove
+        let location = |latitude, longitude| Location {
+            coordinates: Coordinates {
+                latitude,
+                longitude,
+            },
+            address: "Metro Manila".to_string(),
+        };
+
+        let (quoted, quote) = lalamove
             .quote(QuotationRequest {
-                pick_up_location: Location {
-                    coordinates: Coordinates {
-                        latitude: 14.535372967557564,
-                        longitude: 120.98197538196277,
-                    },
-                    address: "SM Mall of Asia, Seaside Boulevard, 123, Pasay, Metro Manila".to_owned(),
-                },
-                service: market_info.regions[0].services[0].service.clone(),
-                stops: [Location {
-                    coordinates: Coordinates {
-                        latitude: 14.586164229973143,
-                        longitude: 121.05665251264826,
-                    },
-                    address: "SM Megamall, Doña Julia Vargas Avenue, Ortigas Center, Mandaluyong, Metro Manila".to_string(),
-                }],
+                pick_up_location: location(14.535372967557564, 120.98197538196277),
+                service: PhilippineServices::from(&market_info.regions[0].services[0].service),
+                stops: [
+                    location(14.586164229973143, 121.05665251264826),
+                    location(14.5547288, 121.0244452),
+                ],
+                is_route_optimized: true,
+                item: None,
+                schedule_at: None,
             })
             .await
             .unwrap();
 
-        let delivery = lalamove
-            .place_order(DeliveryRequest {
-                quoted: quoted_request,
-                sender: PersonInfo {
-                    name: "Alice".to_string(),
-                    phone_number: parse(None, "+639000001024").unwrap(),
-                },
-                recipients_info: [PersonInfo {
-                    name: "Bob".to_string(),
-                    phone_number: parse(None, "+639000000512").unwrap(),
-                }],
-            })
+        assert_eq!(quote.stop_order, vec![1, 0]);
+        assert_eq!(quoted.stop_ids()[0].to_string(), "2");
+        assert_eq!(quoted.stop_ids()[1].to_string(), "3");
+    }
+
+    #[tokio::test]
+    async fn refuses_to_wait_past_the_max_retry_after() {
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path("/v3/cities"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "86400"))
+            .with_priority(1)
+            .expect(1)
+            .mount(&sandbox.server)
+            .await;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config())
+            .with_rate_limit_retries(3)
+            .with_max_retry_after(Duration::from_secs(30));
+
+        let error = lalamove.market_info().await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            RequestError::RateLimited { retry_after: Some(retry_after), attempts: 1 }
+                if retry_after == Duration::from_secs(86400)
+        ));
+
+        sandbox.server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn places_an_order_through_a_boxed_client() {
+        let sandbox = MockSandbox::start().await;
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config()).boxed();
+
+        let delivery = place_sample_order(&lalamove).await;
+
+        assert_eq!(delivery.id.to_string(), ORDER_ID);
+    }
+
+    #[tokio::test]
+    async fn reports_the_failing_endpoint() {
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path(format!("/v3/orders/{ORDER_ID}")))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "errors": [{ "id": "ERR_UNAUTHORIZED", "message": "Unauthorized" }]
+            })))
+            .mount(&sandbox.server)
+            .await;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config());
+
+        let error = lalamove
+            .delivery_status(ORDER_ID.parse().unwrap())
             .await
+            .unwrap_err();
+
+        assert_eq!(error.status(), Some(StatusCode::UNAUTHORIZED));
+        assert!(matches!(
+            &error,
+            RequestError::ApiError { method, path, .. }
+                if *method == Method::GET && path == &format!("/v3/orders/{ORDER_ID}")
+        ));
+        assert!(error
+            .to_string()
+            .starts_with("GET /v3/orders/107900701184 returned 401"));
+    }
+
+    #[tokio::test]
+    async fn sends_the_configured_headers() {
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path("/v3/cities"))
+            .and(header("user-agent", "checkout/1.2.3"))
+            .and(header("x-integration", "storefront"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [] })))
+            .with_priority(1)
+            .expect(1)
+            .mount(&sandbox.server)
+            .await;
+
+        let config = sandbox
+            .config()
+            .with_user_agent("checkout/1.2.3")
+            .unwrap()
+            .with_header("X-Integration", "storefront")
             .unwrap();
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(config);
+
+        lalamove.market_info().await.unwrap();
+
+        sandbox.server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn lets_an_interceptor_answer_without_sending() {
+        struct CannedCities;
+
+        impl Interceptor for CannedCities {
+            fn respond(&self, request: &http::Request<String>) -> Option<HttpResponse> {
+                (request.uri().path() == "/v3/cities").then(|| HttpResponse {
+                    status: StatusCode::OK,
+                    headers: Default::default(),
+                    bytes: br#"{ "data": [] }"#.to_vec(),
+                })
+            }
+        }
+
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path("/v3/cities"))
+            .respond_with(ResponseTemplate::new(503))
+            .with_priority(1)
+            .expect(0)
+            .mount(&sandbox.server)
+            .await;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config())
+            .with_interceptor(CannedCities);
+
+        assert!(lalamove.market_info().await.unwrap().regions.is_empty());
+
+        sandbox.server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn downloads_pods_through_the_request_pipeline() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::PodImage;
+
+        static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+        struct CountRequests;
+
+        impl Interceptor for CountRequests {
+            fn on_request(&self, _request: &mut http::Request<String>) {
+                REQUESTS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path("/pod/1.jpg"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .with_priority(1)
+            .up_to_n_times(1)
+            .mount(&sandbox.server)
+            .await;
+
+        Mock::given(path("/pod/1.jpg"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"jpeg".to_vec()))
+            .with_priority(2)
+            .mount(&sandbox.server)
+            .await;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config())
+            .with_rate_limit_retries(1)
+            .with_interceptor(CountRequests);
+
+        let pod = PodImage {
+            stop_id: serde_json::from_value(json!("1")).unwrap(),
+            url: format!("{}/pod/1.jpg", sandbox.server.uri())
+                .parse()
+                .unwrap(),
+            delivered_at: None,
+        };
+
+        assert_eq!(lalamove.download_pod(&pod).await.unwrap(), b"jpeg");
+        assert_eq!(REQUESTS.load(Ordering::SeqCst), 2);
+
+        let requests = sandbox.server.received_requests().await.unwrap();
+        assert!(requests
+            .iter()
+            .all(|request| !request.headers.contains_key("authorization")));
+    }
+
+    #[tokio::test]
+    async fn overrides_the_market_for_a_single_call() {
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path("/v3/cities"))
+            .and(header("market", "SG"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [] })))
+            .with_priority(1)
+            .expect(1)
+            .mount(&sandbox.server)
+            .await;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config());
+
+        lalamove
+            .for_market::<DynamicMarket>(DynamicLocale::new("SG", "en_SG").unwrap())
+            .market_info()
+            .await
+            .unwrap();
+
+        sandbox.server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn opens_the_circuit_after_repeated_failures() {
+        let sandbox = MockSandbox::start().await;
+
+        Mock::given(path("/v3/cities"))
+            .respond_with(ResponseTemplate::new(503))
+            .with_priority(1)
+            .expect(2)
+            .mount(&sandbox.server)
+            .await;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config())
+            .with_circuit_breaker(2, Duration::from_secs(60));
+
+        for _ in 0..2 {
+            assert!(lalamove.market_info().await.is_err());
+        }
+
+        assert!(matches!(
+            lalamove.market_info().await,
+            Err(RequestError::CircuitOpen { .. })
+        ));
+
+        sandbox.server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn keeps_circuit_failures_across_rate_limits() {
+        let sandbox = MockSandbox::start().await;
+
+        for (priority, status) in [(1, 503), (2, 429)] {
+            Mock::given(path("/v3/cities"))
+                .respond_with(ResponseTemplate::new(status).insert_header("retry-after", "0"))
+                .with_priority(priority)
+                .up_to_n_times(1)
+                .mount(&sandbox.server)
+                .await;
+        }
+
+        Mock::given(path("/v3/cities"))
+            .respond_with(ResponseTemplate::new(503))
+            .with_priority(3)
+            .expect(1)
+            .mount(&sandbox.server)
+            .await;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(sandbox.config())
+            .with_rate_limit_retries(0)
+            .with_circuit_breaker(2, Duration::from_secs(60));
+
+        assert!(lalamove.market_info().await.is_err());
+        assert!(matches!(
+            lalamove.market_info().await,
+            Err(RequestError::RateLimited { attempts: 1, .. })
+        ));
+        assert!(lalamove.market_info().await.is_err());
+
+        assert!(matches!(
+            lalamove.market_info().await,
+            Err(RequestError::CircuitOpen { .. })
+        ));
+
+        sandbox.server.verify().await;
+    }
+
+    #[test]
+    fn rejects_http2_without_alpn() {
+        use crate::{FromTransportConfig, ReqwestTransportError, TransportConfig};
+
+        let http2 =
+            Client::from_transport_config(&TransportConfig::default().with_prefer_http2(true));
+
+        if cfg!(feature = "reqwest-alpn") {
+            assert!(http2.is_ok());
+        } else {
+            assert!(matches!(
+                http2,
+                Err(ReqwestTransportError::Http2Unavailable)
+            ));
+        }
+
+        assert!(Client::from_transport_config(
+            &TransportConfig::default().with_prefer_http2(false)
+        )
+        .is_ok());
+    }
+
+    #[cfg(feature = "e2e")]
+    #[tokio::test]
+    async fn main() {
+        use crate::{Config, PhilippineLanguages};
+        use dotenvy_macro::dotenv;
+
+        let lalamove = Lalamove::<PhilippineMarket, Client>::new(
+            Config::new(
+                dotenv!("LALAMOVE_API_KEY").to_string(),
+                dotenv!("LALAMOVE_API_SECRET").to_string(),
+                PhilippineLanguages::English,
+            )
+            .unwrap(),
+        );
+
+        let delivery = place_sample_order(&lalamove).await;
 
         println!("{delivery:?}");
     }
 }
 
+#[derive(Debug, ThisError)]
+pub enum ReqwestTransportError {
+    #[error(transparent)]
+    ReqwestError(#[from] ReqwestError),
+    #[error("reqwest can only negotiate HTTP/2 over native TLS with the `reqwest-alpn` feature.")]
+    Http2Unavailable,
+}
+
+impl FromTransportConfig for ReqwestClient {
+    type BuildErr = ReqwestTransportError;
+
+    fn from_transport_config(transport: &TransportConfig) -> Result<Self, Self::BuildErr> {
+        let mut builder = ReqwestClient::builder();
+
+        if let Some(idle_timeout) = transport.idle_timeout {
+            builder = builder.pool_idle_timeout(idle_timeout);
+        }
+
+        if let Some(tcp_keep_alive) = transport.tcp_keep_alive {
+            builder = builder.tcp_keepalive(tcp_keep_alive);
+        }
+
+        if let Some(max_connections) = transport.max_connections {
+            builder = builder.pool_max_idle_per_host(max_connections);
+        }
+
+        builder = match transport.prefer_http2 {
+            Some(true) if cfg!(not(feature = "reqwest-alpn")) => {
+                return Err(ReqwestTransportError::Http2Unavailable)
+            }
+            Some(true) => builder.http2_adaptive_window(true),
+            Some(false) => builder.http1_only(),
+            None => builder,
+        };
+
+        if let Some(proxy) = &transport.proxy {
+            let no_proxy = NoProxy::from_string(&proxy.no_proxy.join(","));
+
+            builder = builder.proxy(Proxy::all(proxy.url.to_string())?.no_proxy(no_proxy));
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
 #[derive(Debug, ThisError)]
 pub enum ReqwestClientError {
     #[error(transparent)]
@@ -106,7 +660,12 @@ impl HttpClient for ReqwestClient {
 
         Ok(HttpResponse {
             status: response.status(),
+            headers: response.headers().clone(),
             bytes: Vec::from(response.bytes().await?),
         })
     }
+
+    fn is_connect_error(error: &Self::Err) -> bool {
+        matches!(error, ReqwestClientError::ReqwestError(error) if error.is_connect())
+    }
 }
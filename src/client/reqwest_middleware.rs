@@ -0,0 +1,103 @@
+use std::{error::Error, str::FromStr};
+
+use http::{Error as HttpError, Request};
+use reqwest::{Client as ReqwestClient, Error as ReqwestError};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Error as MiddlewareError};
+
+use async_trait::async_trait;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{
+        Config, FromTransportConfig, HttpClient, HttpResponse, Lalamove, ReqwestTransportError,
+        TransportConfig,
+    },
+    Market, RequestError,
+};
+
+#[derive(Clone)]
+pub struct MiddlewareClient(pub ClientWithMiddleware);
+
+impl Default for MiddlewareClient {
+    fn default() -> Self {
+        MiddlewareClient(ClientBuilder::new(ReqwestClient::new()).build())
+    }
+}
+
+impl From<ClientWithMiddleware> for MiddlewareClient {
+    fn from(client: ClientWithMiddleware) -> Self {
+        MiddlewareClient(client)
+    }
+}
+
+impl<M: Market> Lalamove<M, MiddlewareClient>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    pub fn with_middleware(config: Config<M>, client: ClientWithMiddleware) -> Self {
+        Lalamove::with_client(config, MiddlewareClient(client))
+    }
+}
+
+impl FromTransportConfig for MiddlewareClient {
+    type BuildErr = ReqwestTransportError;
+
+    fn from_transport_config(transport: &TransportConfig) -> Result<Self, Self::BuildErr> {
+        Ok(MiddlewareClient(
+            ClientBuilder::new(ReqwestClient::from_transport_config(transport)?).build(),
+        ))
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum MiddlewareClientError {
+    #[error(transparent)]
+    MiddlewareError(#[from] MiddlewareError),
+    #[error(transparent)]
+    ReqwestError(#[from] ReqwestError),
+    #[error(transparent)]
+    HttpError(#[from] HttpError),
+}
+
+impl From<MiddlewareClientError> for RequestError<MiddlewareClient> {
+    fn from(value: MiddlewareClientError) -> Self {
+        RequestError::HttpClientError(value)
+    }
+}
+
+#[async_trait]
+impl HttpClient for MiddlewareClient {
+    type Err = MiddlewareClientError;
+
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err> {
+        let mut client_request = self
+            .0
+            .request(request.method().to_owned(), request.uri().to_string());
+
+        for (header_name, header_value) in request.headers().iter() {
+            client_request = client_request.header(header_name, header_value)
+        }
+
+        let response = client_request
+            .body(request.body().to_owned())
+            .send()
+            .await?;
+
+        Ok(HttpResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            bytes: Vec::from(response.bytes().await?),
+        })
+    }
+
+    fn is_connect_error(error: &Self::Err) -> bool {
+        use MiddlewareClientError as MCE;
+
+        match error {
+            MCE::MiddlewareError(MiddlewareError::Reqwest(error)) | MCE::ReqwestError(error) => {
+                error.is_connect()
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use http::Method;
+use serde::Serialize;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{ApiEnvironment, ApiPaths, HttpClient, Lalamove, RequestError},
+    DeliveryId, DeliveryStatus, Market,
+};
+
+impl<M: Market, C: HttpClient> Lalamove<M, C>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    pub async fn sandbox_set_status(
+        &self,
+        delivery: DeliveryId,
+        status: DeliveryStatus,
+    ) -> Result<(), SandboxError<C>> {
+        if self.config.environment != ApiEnvironment::Sandbox {
+            return Err(SandboxError::NotSandbox);
+        }
+
+        self.make_empty_request(
+            ApiPaths::SandboxOrderStatus(delivery.clone()),
+            Method::PATCH,
+            Some(ApiSandboxStatusRequest { status }),
+        )
+        .await?;
+
+        if let Some(cache) = &self.order_cache {
+            cache.remove(&delivery);
+        }
+
+        return Ok(());
+
+        #[derive(Serialize, Debug)]
+        struct ApiSandboxStatusRequest {
+            status: DeliveryStatus,
+        }
+    }
+}
+
+#[derive(ThisError)]
+pub enum SandboxError<C: HttpClient> {
+    #[error("Order statuses can only be simulated with sandbox credentials.")]
+    NotSandbox,
+    #[error(transparent)]
+    RequestError(#[from] RequestError<C>),
+}
+
+impl<C: HttpClient> Debug for SandboxError<C>
+where
+    C::Err: Error,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NotSandbox => write!(f, "NotSandbox"),
+            Self::RequestError(e) => write!(f, "RequestError({:?})", e),
+        }
+    }
+}
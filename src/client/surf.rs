@@ -0,0 +1,123 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Error as IoError, Read},
+    str::FromStr,
+};
+
+use flate2::read::{GzDecoder, ZlibDecoder};
+use http::{
+    header::{
+        HeaderName, HeaderValue, ToStrError, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH,
+    },
+    HeaderMap, Request, StatusCode,
+};
+use surf::{
+    http::{url::ParseError as UrlParseError, Method as SurfMethod, Url},
+    Client as SurfClient, Error as SurfError, RequestBuilder,
+};
+
+use async_trait::async_trait;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{HttpClient, HttpResponse},
+    RequestError,
+};
+
+#[derive(Debug, ThisError)]
+pub enum SurfClientError {
+    #[error(transparent)]
+    SurfError(SurfErrorMessage),
+    #[error(transparent)]
+    UrlParseError(#[from] UrlParseError),
+    #[error(transparent)]
+    ToStrError(#[from] ToStrError),
+    #[error(transparent)]
+    DecompressionError(#[from] IoError),
+}
+
+#[derive(Debug)]
+pub struct SurfErrorMessage(pub SurfError);
+
+impl Display for SurfErrorMessage {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+impl Error for SurfErrorMessage {}
+
+impl From<SurfError> for SurfClientError {
+    fn from(error: SurfError) -> Self {
+        SurfClientError::SurfError(SurfErrorMessage(error))
+    }
+}
+
+impl From<SurfClientError> for RequestError<SurfClient> {
+    fn from(value: SurfClientError) -> Self {
+        RequestError::HttpClientError(value)
+    }
+}
+
+#[async_trait]
+impl HttpClient for SurfClient {
+    type Err = SurfClientError;
+
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err> {
+        let method = SurfMethod::from_str(request.method().as_str())?;
+        let mut client_request =
+            RequestBuilder::new(method, Url::parse(&request.uri().to_string())?);
+
+        for (header_name, header_value) in request.headers().iter() {
+            client_request = client_request.header(header_name.as_str(), header_value.to_str()?);
+        }
+
+        if !request.headers().contains_key(ACCEPT_ENCODING) {
+            client_request = client_request.header(ACCEPT_ENCODING.as_str(), "gzip, deflate");
+        }
+
+        let mut response = self
+            .send(client_request.body(request.body().to_owned()))
+            .await?;
+
+        let mut headers = response
+            .iter()
+            .filter_map(|(name, values)| {
+                Some((
+                    HeaderName::from_bytes(name.as_str().as_bytes()).ok()?,
+                    HeaderValue::from_str(values.last().as_str()).ok()?,
+                ))
+            })
+            .collect::<HeaderMap>();
+
+        let bytes = decompress(&mut headers, response.body_bytes().await?)?;
+
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(response.status().into())
+                .expect("Surf should only return valid status codes."),
+            headers,
+            bytes,
+        })
+    }
+}
+
+fn decompress(headers: &mut HeaderMap, bytes: Vec<u8>) -> Result<Vec<u8>, IoError> {
+    let encoding = headers
+        .get(CONTENT_ENCODING)
+        .and_then(|encoding| encoding.to_str().ok())
+        .map(|encoding| encoding.trim().to_lowercase());
+
+    let mut decompressed = Vec::new();
+
+    match encoding.as_deref() {
+        Some("gzip" | "x-gzip") => GzDecoder::new(&bytes[..]).read_to_end(&mut decompressed)?,
+        Some("deflate") => ZlibDecoder::new(&bytes[..]).read_to_end(&mut decompressed)?,
+        _ => return Ok(bytes),
+    };
+
+    headers.remove(CONTENT_ENCODING);
+    headers.remove(CONTENT_LENGTH);
+
+    Ok(decompressed)
+}
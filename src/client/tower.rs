@@ -0,0 +1,77 @@
+use std::{
+    error::Error,
+    future::{poll_fn, Future},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use http::Request;
+use tower_service::Service;
+
+use async_trait::async_trait;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{HttpClient, HttpResponse},
+    RequestError,
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct TowerClient<S>(pub S);
+
+#[derive(Debug, ThisError)]
+#[error(transparent)]
+pub struct TowerClientError<E: Error + 'static>(pub E);
+
+impl<S> From<TowerClientError<S::Error>> for RequestError<TowerClient<S>>
+where
+    S: Service<Request<String>, Response = HttpResponse> + Clone + Send + Sync + 'static,
+    S::Error: Error + Send + Sync + 'static,
+    S::Future: Send,
+{
+    fn from(value: TowerClientError<S::Error>) -> Self {
+        RequestError::HttpClientError(value)
+    }
+}
+
+#[async_trait]
+impl<S> HttpClient for TowerClient<S>
+where
+    S: Service<Request<String>, Response = HttpResponse> + Clone + Send + Sync + 'static,
+    S::Error: Error + Send + Sync + 'static,
+    S::Future: Send,
+{
+    type Err = TowerClientError<S::Error>;
+
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err> {
+        let mut service = self.0.clone();
+
+        poll_fn(|context| service.poll_ready(context))
+            .await
+            .map_err(TowerClientError)?;
+
+        service.call(request).await.map_err(TowerClientError)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct HttpClientService<C>(pub C);
+
+impl<C> Service<Request<String>> for HttpClientService<C>
+where
+    C: HttpClient + Clone + Send + Sync + 'static,
+{
+    type Response = HttpResponse;
+    type Error = C::Err;
+    type Future = Pin<Box<dyn Future<Output = Result<HttpResponse, C::Err>> + Send>>;
+
+    fn poll_ready(&mut self, _context: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<String>) -> Self::Future {
+        let client = self.0.clone();
+
+        Box::pin(async move { client.request(request).await })
+    }
+}
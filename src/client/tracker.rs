@@ -0,0 +1,178 @@
+use std::{error::Error, str::FromStr, time::Duration};
+
+use futures::{
+    future::{select, Either},
+    stream::{unfold, Stream, StreamExt},
+};
+use futures_timer::Delay;
+
+use crate::{
+    client::{HttpClient, Lalamove, RequestError},
+    DeliveryId, DeliveryStatus, Market, WebhookEvent,
+};
+
+#[derive(Debug, Clone)]
+pub struct DeliveryTracker {
+    initial_interval: Duration,
+    max_interval: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusTransition {
+    pub from: Option<DeliveryStatus>,
+    pub to: DeliveryStatus,
+    pub source: TransitionSource,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionSource {
+    Webhook,
+    Polling,
+}
+
+impl Default for DeliveryTracker {
+    fn default() -> Self {
+        const INITIAL_INTERVAL_SECONDS: u64 = 5;
+        const MAX_INTERVAL_SECONDS: u64 = 60;
+
+        DeliveryTracker {
+            initial_interval: Duration::from_secs(INITIAL_INTERVAL_SECONDS),
+            max_interval: Duration::from_secs(MAX_INTERVAL_SECONDS),
+        }
+    }
+}
+
+impl DeliveryTracker {
+    pub fn new() -> Self {
+        DeliveryTracker::default()
+    }
+
+    pub fn with_intervals(mut self, initial_interval: Duration, max_interval: Duration) -> Self {
+        self.initial_interval = initial_interval;
+        self.max_interval = max_interval.max(initial_interval);
+        self
+    }
+
+    pub fn track<'a, M: Market, C: HttpClient, W>(
+        &self,
+        lalamove: &'a Lalamove<M, C>,
+        delivery: DeliveryId,
+        webhooks: W,
+    ) -> impl Stream<Item = Result<StatusTransition, RequestError<C>>> + 'a
+    where
+        <<M as Market>::Languages as FromStr>::Err: Error,
+        W: Stream<Item = WebhookEvent> + Unpin + 'a,
+    {
+        let state = TrackerState {
+            lalamove,
+            delivery,
+            webhooks,
+            webhooks_open: true,
+            started: false,
+            finished: false,
+            last: None,
+            interval: self.initial_interval,
+            poll_timer: Delay::new(self.initial_interval),
+            initial_interval: self.initial_interval,
+            max_interval: self.max_interval,
+        };
+
+        unfold(state, |mut state| async move {
+            loop {
+                if state.finished {
+                    return None;
+                }
+
+                let (status, source) = match state.next_observation().await {
+                    Observation::Webhook(status) => {
+                        state.interval = state.initial_interval;
+                        state.poll_timer.reset(state.interval);
+                        (status, TransitionSource::Webhook)
+                    }
+                    Observation::Poll => {
+                        let polled = state.lalamove.delivery_status(state.delivery.clone()).await;
+                        state.interval = (state.interval * 2).min(state.max_interval);
+                        state.poll_timer.reset(state.interval);
+
+                        match polled {
+                            Ok(status) => (status, TransitionSource::Polling),
+                            Err(error) => return Some((Err(error), state)),
+                        }
+                    }
+                    Observation::Nothing => continue,
+                };
+
+                if state.last.as_ref() == Some(&status) {
+                    continue;
+                }
+
+                state.finished = status.is_terminal();
+
+                let transition = StatusTransition {
+                    from: state.last.replace(status.clone()),
+                    to: status,
+                    source,
+                };
+
+                return Some((Ok(transition), state));
+            }
+        })
+    }
+}
+
+struct TrackerState<'a, M: Market, C: HttpClient, W>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    lalamove: &'a Lalamove<M, C>,
+    delivery: DeliveryId,
+    webhooks: W,
+    webhooks_open: bool,
+    started: bool,
+    finished: bool,
+    last: Option<DeliveryStatus>,
+    interval: Duration,
+    // Shared across observations so that webhooks for other deliveries don't keep pushing the
+    // next poll back.
+    poll_timer: Delay,
+    initial_interval: Duration,
+    max_interval: Duration,
+}
+
+enum Observation {
+    Webhook(DeliveryStatus),
+    Poll,
+    Nothing,
+}
+
+impl<'a, M: Market, C: HttpClient, W> TrackerState<'a, M, C, W>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+    W: Stream<Item = WebhookEvent> + Unpin,
+{
+    async fn next_observation(&mut self) -> Observation {
+        if !self.started {
+            self.started = true;
+            return Observation::Poll;
+        }
+
+        if !self.webhooks_open {
+            (&mut self.poll_timer).await;
+            return Observation::Poll;
+        }
+
+        match select(self.webhooks.next(), &mut self.poll_timer).await {
+            Either::Left((Some(event), _)) => match (event.delivery_id(), event.status()) {
+                (Some(delivery), Some(status)) if *delivery == self.delivery => {
+                    Observation::Webhook(status.clone())
+                }
+                _ => Observation::Nothing,
+            },
+            Either::Left((None, _)) => {
+                self.webhooks_open = false;
+                Observation::Nothing
+            }
+            Either::Right(_) => Observation::Poll,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::{error::Error, str::FromStr, time::Duration};
+
+use http::Uri;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr, DurationSeconds};
+
+use crate::{
+    client::{Config, HttpClient, Lalamove},
+    Market,
+};
+
+#[serde_as]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransportConfig {
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+    /// reqwest has no hard connection limit, so there this caps the idle connections kept per
+    /// host. awc treats it as the maximum number of open connections.
+    #[serde(default)]
+    pub max_connections: Option<usize>,
+    #[serde_as(as = "Option<DurationSeconds<u64>>")]
+    #[serde(default)]
+    pub idle_timeout: Option<Duration>,
+    #[serde_as(as = "Option<DurationSeconds<u64>>")]
+    #[serde(default)]
+    pub tcp_keep_alive: Option<Duration>,
+    /// `None` keeps the backend's own protocol negotiation. reqwest only offers HTTP/2 over
+    /// native TLS when the `reqwest-alpn` feature is enabled, so without it `Some(true)` is
+    /// rejected.
+    #[serde(default)]
+    pub prefer_http2: Option<bool>,
+}
+
+impl TransportConfig {
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = Some(max_connections);
+        self
+    }
+
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    pub fn with_tcp_keep_alive(mut self, tcp_keep_alive: Duration) -> Self {
+        self.tcp_keep_alive = Some(tcp_keep_alive);
+        self
+    }
+
+    pub fn with_prefer_http2(mut self, prefer_http2: bool) -> Self {
+        self.prefer_http2 = Some(prefer_http2);
+        self
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub url: Uri,
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    pub fn new(url: Uri) -> Self {
+        ProxyConfig {
+            url,
+            no_proxy: Vec::new(),
+        }
+    }
+
+    pub fn with_no_proxy(mut self, host: impl Into<String>) -> Self {
+        self.no_proxy.push(host.into());
+        self
+    }
+}
+
+pub trait FromTransportConfig: HttpClient + Sized {
+    type BuildErr: Error;
+
+    fn from_transport_config(transport: &TransportConfig) -> Result<Self, Self::BuildErr>;
+}
+
+impl<M: Market, C: FromTransportConfig> Lalamove<M, C>
+where
+    <<M as Market>::Languages as FromStr>::Err: Error,
+{
+    pub fn with_transport(
+        config: Config<M>,
+        transport: &TransportConfig,
+    ) -> Result<Self, C::BuildErr> {
+        Ok(Lalamove::with_client(
+            config,
+            C::from_transport_config(transport)?,
+        ))
+    }
+}
@@ -0,0 +1,70 @@
+use gloo_net::{http::RequestBuilder, Error as GlooError};
+use http::{
+    header::{HeaderName, HeaderValue},
+    status::InvalidStatusCode,
+    Request, StatusCode,
+};
+
+use async_trait::async_trait;
+use thiserror::Error as ThisError;
+
+use crate::{
+    client::{HttpClient, HttpResponse},
+    RequestError,
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct FetchClient;
+
+#[derive(Debug, ThisError)]
+pub enum FetchClientError {
+    #[error(transparent)]
+    GlooError(#[from] GlooError),
+    #[error(transparent)]
+    InvalidStatusCode(#[from] InvalidStatusCode),
+}
+
+impl From<FetchClientError> for RequestError<FetchClient> {
+    fn from(value: FetchClientError) -> Self {
+        RequestError::HttpClientError(value)
+    }
+}
+
+#[async_trait(?Send)]
+impl HttpClient for FetchClient {
+    type Err = FetchClientError;
+
+    async fn request(&self, request: Request<String>) -> Result<HttpResponse, Self::Err> {
+        let mut client_request =
+            RequestBuilder::new(&request.uri().to_string()).method(request.method().to_owned());
+
+        for (header_name, header_value) in request.headers().iter() {
+            if let Ok(header_value) = header_value.to_str() {
+                client_request = client_request.header(header_name.as_str(), header_value);
+            }
+        }
+
+        let client_request = if request.body().is_empty() {
+            client_request.build()?
+        } else {
+            client_request.body(request.body().to_owned())?
+        };
+
+        let response = client_request.send().await?;
+
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(response.status())?,
+            headers: response
+                .headers()
+                .entries()
+                .filter_map(|(name, value)| {
+                    Some((
+                        HeaderName::from_bytes(name.as_bytes()).ok()?,
+                        HeaderValue::from_str(&value).ok()?,
+                    ))
+                })
+                .collect(),
+            bytes: response.binary().await?,
+        })
+    }
+}
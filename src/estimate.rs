@@ -0,0 +1,83 @@
+use rust_decimal::Decimal;
+use rusty_money::{iso::Currency, Money};
+
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{Meters, Quote, ServiceType};
+
+#[derive(Debug, Clone, Default)]
+pub struct QuoteHistory {
+    samples: Vec<QuoteSample>,
+}
+
+#[derive(Debug, Clone)]
+struct QuoteSample {
+    service: ServiceType,
+    distance: Meters,
+    price: Money<'static, Currency>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimatedQuote {
+    pub service: ServiceType,
+    pub distance: Meters,
+    #[serde_as(as = "DisplayFromStr")]
+    pub price: Money<'static, Currency>,
+    pub sample_count: usize,
+}
+
+impl QuoteHistory {
+    pub fn new() -> Self {
+        QuoteHistory::default()
+    }
+
+    pub fn record(&mut self, service: ServiceType, quote: &Quote) {
+        self.samples.push(QuoteSample {
+            service,
+            distance: quote.distance.clone(),
+            price: quote.price.clone(),
+        });
+    }
+
+    pub fn estimate(&self, service: &ServiceType, distance: Meters) -> Option<EstimatedQuote> {
+        let currency = self
+            .samples
+            .iter()
+            .rev()
+            .find(|sample| sample.service == *service)?
+            .price
+            .currency();
+
+        let rates = self
+            .samples
+            .iter()
+            .filter(|sample| sample.service == *service && sample.price.currency() == currency)
+            .filter_map(|sample| {
+                let kilometers = Decimal::from_f32_retain(sample.distance.0 / 1000.0)?;
+
+                if kilometers.is_zero() {
+                    None
+                } else {
+                    Some(*sample.price.amount() / kilometers)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if rates.is_empty() {
+            return None;
+        }
+
+        let rate = rates.iter().sum::<Decimal>() / Decimal::from(rates.len());
+        let kilometers = Decimal::from_f32_retain(distance.0 / 1000.0)?;
+
+        Some(EstimatedQuote {
+            service: service.clone(),
+            distance,
+            price: Money::from_decimal((rate * kilometers).round_dp(2), currency),
+            sample_count: rates.len(),
+        })
+    }
+}
@@ -1,41 +1,153 @@
 #![feature(generic_const_exprs)]
 
 use std::{
+    collections::HashSet,
     fmt::{Display, Formatter, Result as FmtResult},
-    num::ParseIntError,
+    iter::once,
+    num::{ParseFloatError, ParseIntError},
+    ops::Not,
     str::FromStr,
 };
 
 use cfg_if::cfg_if;
 use thiserror::Error as ThisError;
 
-use http::Uri;
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 use serde_with::{serde_as, DisplayFromStr};
 
-use phonenumber::PhoneNumber;
-use rusty_money::{iso::Currency, Money};
+use phonenumber::parse;
 
+use money::MoneyAmount;
+use phone::{normalize, E164};
+
+mod builder;
+mod currency;
+mod estimate;
 mod markets;
+mod money;
+mod phone;
+#[cfg(feature = "qr")]
+mod qr;
+#[cfg(any(feature = "_client", feature = "_webhook"))]
+mod signing;
+mod timeline;
+mod validation;
+pub mod webhook;
+
+pub use http::Uri;
+pub use phonenumber::{ParseError as PhoneParseError, PhoneNumber};
+pub use rust_decimal::Decimal;
+pub use rusty_money::{iso::Currency, Money};
+
+pub use builder::{DeliveryRequestBuilder, QuotationRequestBuilder, Unset};
+pub use currency::CurrencyConverter;
+pub use estimate::{EstimatedQuote, QuoteHistory};
+
+pub use markets::{
+    Country, CountryError, Dimensions, DynamicLocale, DynamicMarket, InvalidDynamicLocale,
+    Kilograms, Language, Market, MarketInfo, Meters, Region, RegionError, RegionInfo, Service,
+    ServiceArea, ServiceType, SpecialRequest, SpecialRequestType,
+};
 
+#[cfg(feature = "market-hk")]
+pub use markets::{HongKongLanguages, HongKongMarket, HongKongRegions, InvalidHongKongLanguage};
+
+#[cfg(feature = "market-ph")]
 pub use markets::{
-    Country, Dimensions, InvalidPhilippineLanguage, Kilograms, Language, Market, MarketInfo,
-    Meters, PhilippineLanguages, PhilippineMarket, PhilippineRegions, Region, RegionError,
-    RegionInfo, Service, ServiceType, SpecialRequest, SpecialRequestType,
+    InvalidPhilippineLanguage, PhilippineLanguages, PhilippineMarket, PhilippineRegions,
+    PhilippineServices, PhilippineSpecialRequests,
+};
+pub use timeline::{EventSource, OrderEvent, OrderEventKind, OrderTimeline};
+pub use validation::{StopPosition, ValidationReport, Violation};
+pub use webhook::{
+    DriverAssigned, EventOrder, EventSequencer, InvalidWebhookAmount, MemorySequenceStore,
+    OrderAmountChanged, OrderEdited, OrderReplaced, OrderStatusChanged, SequencePoint,
+    SequenceStore, WalletBalanceChanged, Webhook, WebhookEvent, WebhookFilter,
 };
 
+#[cfg(feature = "_webhook")]
+pub use webhook::{WebhookError, WebhookVerifier};
+
+#[cfg(feature = "axum")]
+pub use webhook::{LalamoveWebhook, WebhookRejection};
+
+#[cfg(feature = "webhook-server")]
+pub use webhook::WebhookServer;
+
+#[cfg(feature = "sqlx")]
+pub use webhook::{StoredWebhook, WebhookStore, WebhookStoreError, WEBHOOK_MIGRATION};
+
+#[cfg(feature = "qr")]
+pub use qrcode::types::QrError;
+
+#[cfg(feature = "qr-png")]
+pub use qr::QrPngError;
+
 cfg_if! {
     if #[cfg(feature = "_client")]
     {
         mod client;
-        pub use client::{Config, ConfigError, Lalamove, QuoteError, RequestError};
+        pub use client::{
+            ApiEnvironment, ApiEnvironmentError, BestOrigin, CancelError, Capture, Config,
+            ConfigError, DeliveryTracker, DualEnvironment, DualEnvironmentError,
+            FromTransportConfig, HttpResponse, Instant, Interceptor, Lalamove, Mirrored,
+            OriginPreference, ProxyConfig, QuoteError, RequestError, RequoteError, RequotePolicy,
+            SandboxError, StatusTransition, TransitionSource, TransportConfig,
+        };
+
+        pub use client::protocol;
+
+        #[cfg(feature = "arbitrary")]
+        pub use client::fuzzing;
+
+        #[cfg(feature = "blocking")]
+        pub use client::blocking;
+
+        #[cfg(feature = "metrics")]
+        pub use client::describe_metrics;
+
+        #[cfg(not(any(feature = "awc", feature = "wasm")))]
+        pub use client::{BoxClient, BoxClientError};
+
+        #[cfg(feature = "awc")]
+        pub use client::AwcTransportError;
+
+        #[cfg(feature = "reqwest")]
+        pub use client::ReqwestTransportError;
+
+        #[cfg(feature = "reqwest-middleware")]
+        pub use client::{MiddlewareClient, MiddlewareClientError};
+
+        #[cfg(feature = "isahc")]
+        pub use client::{IsahcClient, IsahcClientError};
+
+        #[cfg(feature = "tower")]
+        pub use client::{HttpClientService, TowerClient, TowerClientError};
+
+        #[cfg(feature = "surf")]
+        pub use client::{SurfClientError, SurfErrorMessage};
+
+        #[cfg(feature = "wasm")]
+        pub use client::{FetchClient, FetchClientError};
+
+        #[cfg(feature = "test-util")]
+        pub use client::{
+            Cassette, CassetteError, Interaction, MockClient, MockClientError, MockRequest,
+            RecordedRequest, RecordedResponse, RecordingClient, RecordingClientError,
+            ReplayClient, ReplayClientError,
+        };
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum DeliveryStatus {
     AssigningDriver,
+    #[serde(rename = "ON_GOING")]
     Ongoing,
     PickedUp,
     Completed,
@@ -50,6 +162,32 @@ pub enum InvalidDeliveryStatus {
     NoDeliveryStatusFound,
 }
 
+impl DeliveryStatus {
+    pub fn is_cancellable(&self) -> bool {
+        self.cancellation_rejection().is_none()
+    }
+
+    pub fn cancellation_rejection(&self) -> Option<CancellationRejection> {
+        use CancellationRejection as CR;
+        use DeliveryStatus as DS;
+
+        match self {
+            DS::AssigningDriver | DS::Ongoing => None,
+            DS::PickedUp => Some(CR::AlreadyPickedUp),
+            status => Some(CR::Closed(status.clone())),
+        }
+    }
+
+    pub const fn is_terminal(&self) -> bool {
+        use DeliveryStatus as DS;
+
+        matches!(
+            self,
+            DS::Completed | DS::Canceled | DS::Rejected | DS::Expired
+        )
+    }
+}
+
 impl FromStr for DeliveryStatus {
     type Err = InvalidDeliveryStatus;
 
@@ -71,9 +209,11 @@ impl FromStr for DeliveryStatus {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
-pub struct DeliveryId(u64);
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeliveryId(#[serde_as(as = "DisplayFromStr")] u64);
 
 impl FromStr for DeliveryId {
     type Err = ParseIntError;
@@ -89,12 +229,40 @@ impl Display for DeliveryId {
     }
 }
 
+#[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(transparent)]
-pub struct DriverId(u64);
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DriverId(#[serde_as(as = "DisplayFromStr")] u64);
 
+impl FromStr for DriverId {
+    type Err = ParseIntError;
+
+    fn from_str(driver_id: &str) -> Result<Self, Self::Err> {
+        Ok(DriverId(driver_id.parse()?))
+    }
+}
+
+impl Display for DriverId {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ChangeDriverReason {
+    DriverLate,
+    DriverAskedChange,
+    DriverUnresponsive,
+    DriverRude,
+}
+
+/// Serialized in camelCase with ids as strings, mirroring the Lalamove API's own payloads.
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DeliveryRequest<const RECIPIENT_STOP_COUNT: usize>
 where
     Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
@@ -103,16 +271,62 @@ where
     pub sender: PersonInfo,
     #[serde_as(as = "[_; RECIPIENT_STOP_COUNT]")]
     pub recipients_info: [PersonInfo; RECIPIENT_STOP_COUNT],
+    #[serde_as(as = "[Option<MoneyAmount>; RECIPIENT_STOP_COUNT]")]
+    pub cash_on_delivery: [Option<Money<'static, Currency>>; RECIPIENT_STOP_COUNT],
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub metadata: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub preferred_driver_ids: Vec<DriverId>,
+    #[serde(default)]
+    pub payment_method: PaymentMethod,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum PaymentMethod {
+    #[default]
+    Wallet,
+    Cash,
 }
 
+#[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PersonInfo {
     pub name: String,
+    #[serde_as(as = "E164")]
     pub phone_number: PhoneNumber,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remarks: Option<String>,
 }
 
+impl PersonInfo {
+    pub fn new(name: impl Into<String>, phone_number: &str) -> Result<Self, PhoneParseError> {
+        Ok(PersonInfo {
+            name: name.into(),
+            phone_number: parse(None, phone_number)?,
+            remarks: None,
+        })
+    }
+
+    pub fn normalized(&self) -> Result<Self, PhoneParseError> {
+        Ok(PersonInfo {
+            name: self.name.trim().to_string(),
+            phone_number: normalize(&self.phone_number)?,
+            remarks: self.remarks.clone(),
+        })
+    }
+}
+
+/// The ids Lalamove assigned to a quotation and its stops.
+///
+/// Serializing a `QuotedRequest` and deserializing it again always yields an equal value, so it can
+/// be stored after `Lalamove::quote` and handed to `Lalamove::place_order` from another process.
+/// Deserialization doesn't re-run the checks in [`QuotedRequest::from_parts`].
 #[serde_as]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct QuotedRequest<const RECIPIENT_STOP_COUNT: usize>
 where
     Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
@@ -123,43 +337,396 @@ where
     stop_ids: [StopId; RECIPIENT_STOP_COUNT],
 }
 
+impl<const RECIPIENT_STOP_COUNT: usize> QuotedRequest<RECIPIENT_STOP_COUNT>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    pub fn from_parts(
+        quotation_id: QuotationId,
+        pick_up_stop_id: StopId,
+        stop_ids: [StopId; RECIPIENT_STOP_COUNT],
+    ) -> Result<Self, QuotedRequestError> {
+        let mut seen = HashSet::new();
+
+        for stop_id in once(&pick_up_stop_id).chain(stop_ids.iter()) {
+            if !seen.insert(stop_id) {
+                return Err(QuotedRequestError::DuplicateStopId(stop_id.clone()));
+            }
+        }
+
+        Ok(QuotedRequest {
+            quotation_id,
+            pick_up_stop_id,
+            stop_ids,
+        })
+    }
+
+    pub fn quotation_id(&self) -> &QuotationId {
+        &self.quotation_id
+    }
+
+    pub fn pick_up_stop_id(&self) -> &StopId {
+        &self.pick_up_stop_id
+    }
+
+    pub fn stop_ids(&self) -> &[StopId; RECIPIENT_STOP_COUNT] {
+        &self.stop_ids
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum QuotedRequestError {
+    #[error("The stop ID '{0}' was used for more than one stop.")]
+    DuplicateStopId(StopId),
+}
+
+/// Serialized in camelCase, mirroring the Lalamove API's own payloads.
+///
+/// `S` is the market's [`Market::Services`], so a `Lalamove<PhilippineMarket, _>` takes a
+/// `PhilippineServices` here.
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct QuotationRequest<const RECIPIENT_STOP_COUNT: usize>
+#[serde(rename_all = "camelCase")]
+pub struct QuotationRequest<const RECIPIENT_STOP_COUNT: usize, S = ServiceType>
 where
     Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
 {
-    pub service: ServiceType,
+    pub service: S,
     pub pick_up_location: Location,
     #[serde_as(as = "[_; RECIPIENT_STOP_COUNT]")]
     pub stops: [Location; RECIPIENT_STOP_COUNT],
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub is_route_optimized: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<Item>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_at: Option<DateTime<Utc>>,
+}
+
+impl<const RECIPIENT_STOP_COUNT: usize, S> QuotationRequest<RECIPIENT_STOP_COUNT, S>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    pub fn with_stop(self, stop: Location) -> QuotationRequest<{ RECIPIENT_STOP_COUNT + 1 }, S>
+    where
+        Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT + 1) }>: IsTrue,
+        [Location; RECIPIENT_STOP_COUNT + 1]: Sized,
+    {
+        QuotationRequest {
+            service: self.service,
+            pick_up_location: self.pick_up_location,
+            stops: self
+                .stops
+                .into_iter()
+                .chain(once(stop))
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("This shouldn't fail because the stops array's size is RECIPIENT_STOP_COUNT + 1."),
+            is_route_optimized: self.is_route_optimized,
+            item: self.item,
+            schedule_at: self.schedule_at,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Item {
+    #[serde_as(as = "DisplayFromStr")]
+    pub quantity: u32,
+    pub weight: ItemWeight,
+    #[serde(default)]
+    pub categories: Vec<ItemCategory>,
+    #[serde(default)]
+    pub handling_instructions: Vec<HandlingInstruction>,
+    /// Lalamove doesn't take item dimensions, so these are only used by `validate` to check the
+    /// item against the service's cargo space.
+    #[serde(skip)]
+    pub dimensions: Option<Dimensions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ItemWeight(pub String);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ItemCategory(pub String);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HandlingInstruction(pub String);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamicQuotationRequest {
+    pub service: ServiceType,
+    pub pick_up_location: Location,
+    pub stops: Vec<Location>,
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub is_route_optimized: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<Item>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_at: Option<DateTime<Utc>>,
+}
+
+impl DynamicQuotationRequest {
+    pub fn push_stop(&mut self, stop: Location) {
+        self.stops.push(stop);
+    }
+}
+
+impl<const RECIPIENT_STOP_COUNT: usize, S: Into<ServiceType>>
+    From<QuotationRequest<RECIPIENT_STOP_COUNT, S>> for DynamicQuotationRequest
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    fn from(request: QuotationRequest<RECIPIENT_STOP_COUNT, S>) -> Self {
+        DynamicQuotationRequest {
+            service: request.service.into(),
+            pick_up_location: request.pick_up_location,
+            stops: Vec::from(request.stops),
+            is_route_optimized: request.is_route_optimized,
+            item: request.item,
+            schedule_at: request.schedule_at,
+        }
+    }
+}
+
+impl<const RECIPIENT_STOP_COUNT: usize> TryFrom<DynamicQuotationRequest>
+    for QuotationRequest<RECIPIENT_STOP_COUNT>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    type Error = StopCountError;
+
+    fn try_from(request: DynamicQuotationRequest) -> Result<Self, Self::Error> {
+        let found = request.stops.len();
+
+        Ok(QuotationRequest {
+            service: request.service,
+            pick_up_location: request.pick_up_location,
+            stops: request
+                .stops
+                .try_into()
+                .map_err(|_| StopCountError::Mismatch {
+                    expected: RECIPIENT_STOP_COUNT,
+                    found,
+                })?,
+            is_route_optimized: request.is_route_optimized,
+            item: request.item,
+            schedule_at: request.schedule_at,
+        })
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum StopCountError {
+    #[error("Expected {expected} recipient stops but found {found}.")]
+    Mismatch { expected: usize, found: usize },
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Coordinates {
     pub latitude: f64,
     pub longitude: f64,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Location {
     pub coordinates: Coordinates,
     pub address: String,
 }
 
+impl Coordinates {
+    const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+    pub fn distance_to(&self, other: &Coordinates) -> Meters {
+        let latitude = self.latitude.to_radians();
+        let other_latitude = other.latitude.to_radians();
+
+        let half_chord = ((other_latitude - latitude) / 2.0).sin().powi(2)
+            + latitude.cos()
+                * other_latitude.cos()
+                * ((other.longitude - self.longitude).to_radians() / 2.0)
+                    .sin()
+                    .powi(2);
+
+        Meters((2.0 * Self::EARTH_RADIUS_METERS * half_chord.sqrt().asin()) as f32)
+    }
+}
+
+impl From<(f64, f64)> for Coordinates {
+    fn from((latitude, longitude): (f64, f64)) -> Self {
+        Coordinates {
+            latitude,
+            longitude,
+        }
+    }
+}
+
+impl<A: Into<String>> From<(Coordinates, A)> for Location {
+    fn from((coordinates, address): (Coordinates, A)) -> Self {
+        Location {
+            coordinates,
+            address: address.into(),
+        }
+    }
+}
+
+impl From<(f64, f64, String)> for Location {
+    fn from((latitude, longitude, address): (f64, f64, String)) -> Self {
+        Location::from(((latitude, longitude).into(), address))
+    }
+}
+
+impl From<(f64, f64, &str)> for Location {
+    fn from((latitude, longitude, address): (f64, f64, &str)) -> Self {
+        Location::from(((latitude, longitude).into(), address))
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum InvalidLocation {
+    #[error("The location didn't have a '|' separating the coordinates from the address.")]
+    MissingAddress,
+    #[error("The coordinates didn't have a ',' separating the latitude from the longitude.")]
+    MissingLongitude,
+    #[error("Couldn't parse the latitude: {0}")]
+    InvalidLatitude(ParseFloatError),
+    #[error("Couldn't parse the longitude: {0}")]
+    InvalidLongitude(ParseFloatError),
+}
+
+impl FromStr for Location {
+    type Err = InvalidLocation;
+
+    fn from_str(location: &str) -> Result<Self, Self::Err> {
+        use InvalidLocation as IL;
+
+        let (coordinates, address) = location.split_once('|').ok_or(IL::MissingAddress)?;
+        let (latitude, longitude) = coordinates.split_once(',').ok_or(IL::MissingLongitude)?;
+
+        Ok(Location {
+            coordinates: Coordinates {
+                latitude: latitude.trim().parse().map_err(IL::InvalidLatitude)?,
+                longitude: longitude.trim().parse().map_err(IL::InvalidLongitude)?,
+            },
+            address: address.trim().to_string(),
+        })
+    }
+}
+
+impl TryFrom<&str> for Location {
+    type Error = InvalidLocation;
+
+    fn try_from(location: &str) -> Result<Self, Self::Error> {
+        Location::from_str(location)
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Quote {
     pub distance: Meters,
     #[serde_as(as = "DisplayFromStr")]
     pub price: Money<'static, Currency>,
+    pub fees: Vec<Fee>,
+    pub price_breakdown: PriceBreakdown,
+    pub stop_order: Vec<usize>,
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct QuotationId(u64);
+impl Quote {
+    pub fn price_str(&self) -> String {
+        format!(
+            "{} {}",
+            self.price.amount(),
+            self.price.currency().iso_alpha_code
+        )
+    }
+}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StopId(u64);
+impl Display for Quote {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{} for {}", self.price, self.distance)
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceBreakdown {
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub base: Option<Money<'static, Currency>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub extra_mileage: Option<Money<'static, Currency>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub surcharge: Option<Money<'static, Currency>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub priority_fee: Option<Money<'static, Currency>>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub total_exclude_priority_fee: Option<Money<'static, Currency>>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub total: Money<'static, Currency>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub currency: &'static Currency,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fee {
+    pub kind: FeeKind,
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: Money<'static, Currency>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum FeeKind {
+    Base,
+    Distance,
+    Surcharge,
+    Priority,
+    SpecialRequest,
+    Vat,
+    Other(String),
+}
+
+impl From<&str> for FeeKind {
+    fn from(breakdown_key: &str) -> Self {
+        use FeeKind as FK;
+
+        match breakdown_key {
+            "base" => FK::Base,
+            "extraMileage" => FK::Distance,
+            "surcharge" | "multiStopSurcharge" => FK::Surcharge,
+            "priorityFee" => FK::Priority,
+            "specialRequests" => FK::SpecialRequest,
+            "vat" => FK::Vat,
+            other => FK::Other(other.to_string()),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct QuotationId(#[serde_as(as = "DisplayFromStr")] u64);
+
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct StopId(#[serde_as(as = "DisplayFromStr")] u64);
 
 impl Display for QuotationId {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
@@ -191,20 +758,891 @@ impl FromStr for StopId {
 
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct Delivery {
     pub id: DeliveryId,
     #[serde_as(as = "DisplayFromStr")]
     pub share_link: Uri,
+    pub status: DeliveryStatus,
+    pub distance: Option<Meters>,
+    pub stop_ids: Vec<StopId>,
+    pub price: Option<ChargedAmount>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderDetails {
+    pub id: DeliveryId,
+    pub status: DeliveryStatus,
+    #[serde_as(as = "DisplayFromStr")]
+    pub share_link: Uri,
+    pub driver_id: Option<DriverId>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub driver_assigned_at: Option<DateTime<Utc>>,
+    pub charged: Option<ChargedAmount>,
+    pub driver: Option<DriverInfo>,
+    pub pod_images: Vec<PodImage>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PodImage {
+    pub stop_id: StopId,
+    #[serde_as(as = "DisplayFromStr")]
+    pub url: Uri,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DriverInfo {
+    pub name: String,
+    pub masked_phone: String,
+    pub plate_number: String,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChargedAmount {
+    #[serde_as(as = "MoneyAmount")]
+    pub total: Money<'static, Currency>,
+    #[serde_as(as = "Option<MoneyAmount>")]
+    pub base: Option<Money<'static, Currency>>,
+    #[serde_as(as = "Option<MoneyAmount>")]
+    pub extra_mileage: Option<Money<'static, Currency>>,
+    #[serde_as(as = "Option<MoneyAmount>")]
+    pub surcharge: Option<Money<'static, Currency>>,
+    #[serde_as(as = "Option<MoneyAmount>")]
+    pub priority_fee: Option<Money<'static, Currency>>,
+}
+
+impl From<PriceBreakdown> for ChargedAmount {
+    fn from(breakdown: PriceBreakdown) -> Self {
+        ChargedAmount {
+            total: breakdown.total,
+            base: breakdown.base,
+            extra_mileage: breakdown.extra_mileage,
+            surcharge: breakdown.surcharge,
+            priority_fee: breakdown.priority_fee,
+        }
+    }
+}
+
+impl Display for ChargedAmount {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.total)
+    }
+}
+
+impl OrderDetails {
+    pub fn assignment_latency(&self) -> Option<Duration> {
+        Some(self.driver_assigned_at? - self.created_at?)
+    }
+
+    pub fn cancellation_policy(&self) -> CancellationPolicy {
+        use CancellationPolicy as CP;
+        use DeliveryStatus as DS;
+
+        const CANCELLATION_WINDOW_MINUTES: i64 = 5;
+
+        match self.status {
+            DS::AssigningDriver => CP::Free,
+            DS::Ongoing => CP::Limited {
+                until: self.driver_assigned_at.map(|assigned_at| {
+                    assigned_at + Duration::minutes(CANCELLATION_WINDOW_MINUTES)
+                }),
+            },
+            DS::PickedUp | DS::Completed | DS::Canceled | DS::Rejected | DS::Expired => {
+                CP::NotAllowed
+            }
+        }
+    }
+
+    pub fn is_cancellable(&self) -> bool {
+        self.cancellation_rejection().is_none()
+    }
+
+    pub fn cancellation_rejection(&self) -> Option<CancellationRejection> {
+        use CancellationPolicy as CP;
+
+        if let Some(rejection) = self.status.cancellation_rejection() {
+            return Some(rejection);
+        }
+
+        match self.cancellation_policy() {
+            CP::Limited { until: Some(until) } if Utc::now() >= until => {
+                Some(CancellationRejection::WindowElapsed)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, ThisError)]
+pub enum CancellationRejection {
+    #[error("The driver has already picked up the order.")]
+    AlreadyPickedUp,
+    #[error("The order can't be cancelled because its status is {0:?}.")]
+    Closed(DeliveryStatus),
+    #[error("The window for cancelling the order after a driver was assigned has passed.")]
+    WindowElapsed,
+    #[error("Lalamove refused to cancel the order.")]
+    Forbidden,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CancellationPolicy {
+    Free,
+    Limited { until: Option<DateTime<Utc>> },
+    NotAllowed,
 }
 
+pub const MAX_RECIPIENT_STOPS: usize = 15;
+
 pub const fn valid_recipient_stop_count(stop_count: usize) -> bool {
-    const MAX_STOPS: usize = 15;
     const MIN_STOPS: usize = 1;
 
-    stop_count >= MIN_STOPS && stop_count <= MAX_STOPS
+    stop_count >= MIN_STOPS && stop_count <= MAX_RECIPIENT_STOPS
 }
 
 #[derive(Debug)]
 pub struct Assert<const CONDITION: bool> {}
 pub trait IsTrue {}
 impl IsTrue for Assert<true> {}
+
+#[cfg(test)]
+mod tests {
+    use phonenumber::parse;
+    use serde_json::{from_value, json, to_value};
+
+    use crate::{
+        Coordinates, InvalidLocation, Location, PersonInfo, QuotationId, QuotationRequest,
+        QuotedRequest, ServiceType, StopId,
+    };
+
+    #[test]
+    fn parses_location_strings() {
+        let location: Location = "14.5353, 120.9819 | SM Mall of Asia, Pasay"
+            .parse()
+            .unwrap();
+
+        assert_eq!(location.coordinates.latitude, 14.5353);
+        assert_eq!(location.coordinates.longitude, 120.9819);
+        assert_eq!(location.address, "SM Mall of Asia, Pasay");
+
+        assert!(matches!(
+            Location::try_from("14.5353,120.9819"),
+            Err(InvalidLocation::MissingAddress)
+        ));
+        assert!(matches!(
+            Location::try_from("north,120.9819|Pasay"),
+            Err(InvalidLocation::InvalidLatitude(_))
+        ));
+    }
+
+    #[test]
+    fn serializes_requests_in_camel_case() {
+        let location = Location {
+            coordinates: Coordinates {
+                latitude: 14.5,
+                longitude: 121.0,
+            },
+            address: "Pasay".to_string(),
+        };
+
+        let request = QuotationRequest {
+            service: from_value::<ServiceType>(json!("MOTORCYCLE")).unwrap(),
+            pick_up_location: location.clone(),
+            stops: [location],
+            is_route_optimized: false,
+            item: None,
+            schedule_at: None,
+        };
+
+        let quoted = QuotedRequest::from_parts(QuotationId(1), StopId(2), [StopId(3)]).unwrap();
+
+        assert_eq!(
+            to_value(&request).unwrap(),
+            json!({
+                "service": "MOTORCYCLE",
+                "pickUpLocation": {
+                    "coordinates": { "latitude": 14.5, "longitude": 121.0 },
+                    "address": "Pasay"
+                },
+                "stops": [{
+                    "coordinates": { "latitude": 14.5, "longitude": 121.0 },
+                    "address": "Pasay"
+                }]
+            })
+        );
+        assert_eq!(
+            to_value(&quoted).unwrap(),
+            json!({ "quotationId": "1", "pickUpStopId": "2", "stopIds": ["3"] })
+        );
+        assert_eq!(
+            from_value::<QuotedRequest<1>>(to_value(&quoted).unwrap()).unwrap(),
+            quoted
+        );
+    }
+
+    #[test]
+    fn serializes_phone_numbers_in_e164() {
+        let person = PersonInfo {
+            name: " Alice ".to_string(),
+            phone_number: parse(None, "+63 900 000 1024").unwrap(),
+            remarks: None,
+        };
+
+        assert_eq!(
+            to_value(&person).unwrap(),
+            json!({ "name": " Alice ", "phoneNumber": "+639000001024" })
+        );
+        assert_eq!(person.normalized().unwrap().name, "Alice");
+    }
+
+    #[test]
+    #[cfg(feature = "_client")]
+    fn signs_requests() {
+        use http::Method;
+
+        use crate::protocol::sign;
+
+        let signature = sign(
+            "sk_test_secret",
+            1545880607433,
+            &Method::GET,
+            "/v3/cities",
+            "",
+        );
+
+        assert_eq!(
+            signature,
+            "4d3bbccfed8494dde1402ee2f7f1bb91f299bacb31b04c85fc93551e47b5a36f"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "_client", feature = "market-ph"))]
+    fn builds_signed_requests() {
+        use http::{header::AUTHORIZATION, Method};
+
+        use crate::{protocol::build_request, Config, PhilippineLanguages, PhilippineMarket};
+
+        let config = Config::<PhilippineMarket>::new(
+            "pk_test_key".to_string(),
+            "sk_test_secret".to_string(),
+            PhilippineLanguages::English,
+        )
+        .unwrap();
+
+        let request = build_request(
+            &config,
+            "https://rest.sandbox.lalamove.com",
+            "/v3/quotations",
+            Method::POST,
+            Some(json!({ "serviceType": "MOTORCYCLE" })),
+            1545880607433,
+        )
+        .unwrap();
+
+        assert_eq!(
+            request.uri(),
+            "https://rest.sandbox.lalamove.com/v3/quotations"
+        );
+        assert_eq!(request.body(), r#"{"data":{"serviceType":"MOTORCYCLE"}}"#);
+        assert_eq!(
+            request.headers()[AUTHORIZATION],
+            "hmac pk_test_key:1545880607433:\
+             d51282a3c0168f5cd9d6366aa88b5f4544173ed7d674d0f439b77353e7b24e6c"
+        );
+        assert_eq!(request.headers()["Market"], "PH");
+    }
+
+    #[test]
+    #[cfg(feature = "_client")]
+    fn parses_api_errors_from_responses() {
+        use http::{HeaderMap, StatusCode};
+        use serde_json::Value;
+
+        use crate::{
+            protocol::{parse_response, EnvelopeError},
+            HttpResponse,
+        };
+
+        let response = HttpResponse {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            headers: HeaderMap::new(),
+            bytes: br#"{"errors":[{"id":"ERR_INVALID_FIELD"}]}"#.to_vec(),
+        };
+
+        assert!(matches!(
+            parse_response::<Value>(response),
+            Err(EnvelopeError::ApiError(error)) if error.error_ids() == ["ERR_INVALID_FIELD"]
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "market-ph")]
+    fn finds_regions_from_coordinates() {
+        use crate::{PhilippineRegions, Region};
+
+        let region = |latitude, longitude| {
+            Region::from_coordinates(&Coordinates {
+                latitude,
+                longitude,
+            })
+        };
+
+        assert!(matches!(
+            region(14.5547, 121.0244),
+            Some(Region::Philippines(PhilippineRegions::Manila))
+        ));
+        assert!(matches!(
+            region(15.1450, 120.5887),
+            Some(Region::Philippines(PhilippineRegions::Pampanga))
+        ));
+        assert!(matches!(
+            region(10.6407, 122.9689),
+            Some(Region::Philippines(PhilippineRegions::Bacolod))
+        ));
+        assert!(region(0.0, 0.0).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "market-ph")]
+    fn round_trips_config_enums() {
+        use crate::{Country, PhilippineLanguages, PhilippineRegions, Region};
+
+        assert_eq!(to_value(Country::Philippines).unwrap(), json!("PH"));
+        assert_eq!(
+            from_value::<Country>(json!("ph")).unwrap(),
+            Country::Philippines
+        );
+
+        let region = from_value::<Region>(json!("PH MNL")).unwrap();
+        assert!(matches!(
+            region,
+            Region::Philippines(PhilippineRegions::Manila)
+        ));
+        assert_eq!(to_value(&region).unwrap(), json!("PH MNL"));
+
+        let region = from_value::<Region>(json!("PH ZAM")).unwrap();
+        assert!(matches!(&region, Region::Other(locode) if locode == "PH ZAM"));
+        assert_eq!(region.country(), Some(Country::Philippines));
+        assert_eq!(to_value(&region).unwrap(), json!("PH ZAM"));
+
+        let language = from_value::<PhilippineLanguages>(json!("en_PH")).unwrap();
+        assert_eq!(to_value(&language).unwrap(), json!("en_PH"));
+    }
+
+    #[test]
+    fn validates_dynamic_locales() {
+        use crate::{DynamicLocale, Language};
+
+        let locale = DynamicLocale::new("sg", "en_SG").unwrap();
+        assert_eq!(locale.country_code(), "SG");
+        assert!(locale.country().is_none());
+
+        assert!(DynamicLocale::new("SG\r\n", "en_SG").is_ok());
+        assert!(DynamicLocale::new("S\nG", "en_SG").is_err());
+        assert!(DynamicLocale::new("SGP", "en_SG").is_err());
+        assert!("en_S G".parse::<DynamicLocale>().is_err());
+        assert!(from_value::<DynamicLocale>(json!({
+            "countryCode": "S:G",
+            "languageCode": "en_SG",
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn orders_timeline_events_chronologically() {
+        use crate::{
+            DeliveryId, DeliveryStatus, EventSource, OrderDetails, OrderEventKind, OrderTimeline,
+            WebhookEvent,
+        };
+
+        let delivery = from_value::<DeliveryId>(json!("100")).unwrap();
+        let at = |time: &str| {
+            format!("2023-09-01T{time}Z")
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .unwrap()
+        };
+
+        let details = OrderDetails {
+            id: delivery.clone(),
+            status: DeliveryStatus::Completed,
+            share_link: "https://share.lalamove.com/100".parse().unwrap(),
+            driver_id: None,
+            created_at: Some(at("10:00:00")),
+            updated_at: Some(at("10:45:00")),
+            driver_assigned_at: Some(at("10:05:00")),
+            charged: None,
+            driver: None,
+            pod_images: Vec::new(),
+        };
+
+        let picked_up = |order_id: &str| {
+            from_value::<WebhookEvent>(json!({
+                "eventType": "ORDER_STATUS_CHANGED",
+                "data": {
+                    "order": { "orderId": order_id, "status": "PICKED_UP" },
+                    "updatedAt": "2023-09-01T10:20:00Z",
+                },
+            }))
+            .unwrap()
+        };
+
+        let mut timeline = OrderTimeline::new(delivery);
+        timeline.record_details(&details);
+        timeline.record_webhook(picked_up("200"));
+        timeline.record_webhook(picked_up("100"));
+        timeline.record_polled_status(DeliveryStatus::Ongoing, at("10:10:00"));
+
+        let events = timeline.timeline();
+
+        assert!(matches!(
+            events
+                .iter()
+                .map(|event| (event.source, &event.kind))
+                .collect::<Vec<_>>()[..],
+            [
+                (EventSource::OrderDetails, OrderEventKind::Created),
+                (EventSource::OrderDetails, OrderEventKind::DriverAssigned),
+                (
+                    EventSource::Polling,
+                    OrderEventKind::StatusChanged(DeliveryStatus::Ongoing)
+                ),
+                (EventSource::Webhook, OrderEventKind::Webhook(_)),
+                (
+                    EventSource::OrderDetails,
+                    OrderEventKind::StatusChanged(DeliveryStatus::Completed)
+                ),
+            ]
+        ));
+    }
+
+    #[test]
+    fn parses_unknown_webhook_events_with_data() {
+        use crate::{Decimal, Webhook, WebhookEvent};
+
+        let webhook = |event_type: &str, data: serde_json::Value| {
+            from_value::<Webhook>(json!({
+                "apiKey": "pk_test_key",
+                "timestamp": 1545880607,
+                "signature": "",
+                "eventId": "1",
+                "eventType": event_type,
+                "eventVersion": "v3",
+                "data": data,
+            }))
+        };
+
+        let eta_changed = webhook(
+            "ORDER_ETA_CHANGED",
+            json!({ "order": { "orderId": "100" }, "updatedAt": "2023-09-01T00:00:00Z" }),
+        )
+        .unwrap();
+        assert!(matches!(eta_changed.event, WebhookEvent::Other(_)));
+
+        let amount_changed = webhook(
+            "ORDER_AMOUNT_CHANGED",
+            json!({
+                "order": {
+                    "orderId": "100",
+                    "priceBreakdown": {
+                        "base": "90",
+                        "priorityFee": "10",
+                        "total": "100",
+                        "currency": "PHP",
+                    },
+                },
+                "updatedAt": "2023-09-01T00:00:00Z",
+            }),
+        )
+        .unwrap();
+        let WebhookEvent::OrderAmountChanged(amount_changed) = amount_changed.event else {
+            panic!("expected an ORDER_AMOUNT_CHANGED event");
+        };
+        assert_eq!(*amount_changed.total.amount(), Decimal::from(100));
+        assert!(amount_changed.price_breakdown.priority_fee.is_some());
+
+        assert!(webhook("ORDER_STATUS_CHANGED", json!({ "order": {} })).is_err());
+    }
+
+    #[test]
+    fn filters_webhooks() {
+        use crate::{DeliveryId, Webhook, WebhookFilter};
+
+        let webhook = |event_type: &str, data: serde_json::Value| {
+            from_value::<Webhook>(json!({
+                "apiKey": "pk_test_key",
+                "timestamp": 1545880607,
+                "signature": "",
+                "eventId": "1",
+                "eventType": event_type,
+                "eventVersion": "v3",
+                "data": data,
+            }))
+            .unwrap()
+        };
+
+        let picked_up = |order_id: &str| {
+            webhook(
+                "ORDER_STATUS_CHANGED",
+                json!({
+                    "order": { "orderId": order_id, "status": "PICKED_UP" },
+                    "updatedAt": "2023-09-01T00:00:00Z",
+                }),
+            )
+        };
+
+        let wallet = webhook(
+            "WALLET_BALANCE_CHANGED",
+            json!({
+                "balance": { "amount": "1500", "currency": "PHP" },
+                "threshold": { "amount": "2000", "currency": "PHP" },
+                "updatedAt": "2023-09-01T00:00:00Z",
+            }),
+        );
+
+        assert!(WebhookFilter::new().matches(&wallet));
+
+        let filter = WebhookFilter::new()
+            .with_event_types(["ORDER_STATUS_CHANGED"])
+            .with_deliveries([from_value::<DeliveryId>(json!("100")).unwrap()]);
+
+        assert!(filter.matches(&picked_up("100")));
+        assert!(!filter.matches(&picked_up("200")));
+        assert!(!filter.matches(&wallet));
+
+        let eta_changed = webhook(
+            "ORDER_ETA_CHANGED",
+            json!({ "order": { "orderId": "100" }, "updatedAt": "2023-09-01T00:00:00Z" }),
+        );
+
+        assert_eq!(eta_changed.event.event_type(), "ORDER_ETA_CHANGED");
+        assert!(WebhookFilter::new()
+            .with_event_types(["ORDER_ETA_CHANGED"])
+            .matches(&eta_changed));
+        assert!(!filter.matches(&eta_changed));
+    }
+
+    #[test]
+    #[cfg(feature = "market-ph")]
+    fn validates_parcel_fit_and_schedule_window() {
+        use chrono::{Duration, Utc};
+
+        use crate::{
+            Dimensions, Item, ItemWeight, Kilograms, MarketInfo, Meters, PhilippineRegions, Region,
+            RegionInfo, Service, Violation,
+        };
+
+        let motorcycle = from_value::<ServiceType>(json!("MOTORCYCLE")).unwrap();
+
+        let market_info: MarketInfo = MarketInfo {
+            regions: vec![RegionInfo {
+                region: Region::Philippines(PhilippineRegions::Manila),
+                services: vec![Service {
+                    service: motorcycle.clone(),
+                    description: "Motorcycle".to_string(),
+                    dimensions: Dimensions {
+                        width: Meters(0.5),
+                        height: Meters(0.5),
+                        length: Meters(0.5),
+                    },
+                    load: Kilograms(20.0),
+                    special_requests: Vec::new(),
+                }],
+            }],
+        };
+
+        let location = Location {
+            coordinates: Coordinates {
+                latitude: 14.5,
+                longitude: 121.0,
+            },
+            address: "Pasay".to_string(),
+        };
+
+        let item = |weight: &str| Item {
+            quantity: 1,
+            weight: ItemWeight(weight.to_string()),
+            categories: Vec::new(),
+            handling_instructions: Vec::new(),
+            dimensions: Some(Dimensions {
+                width: Meters(0.3),
+                height: Meters(0.2),
+                length: Meters(0.4),
+            }),
+        };
+
+        let mut request = QuotationRequest {
+            service: motorcycle,
+            pick_up_location: location.clone(),
+            stops: [location],
+            is_route_optimized: false,
+            item: Some(item("LESS_THAN_3KG")),
+            schedule_at: Some(Utc::now() + Duration::days(1)),
+        };
+
+        assert!(request.validate(&market_info).is_ok());
+
+        request.item = Some(item("BETWEEN_50KG_AND_100KG"));
+
+        let violations = request.validate(&market_info).unwrap_err().violations;
+        assert!(matches!(violations[..], [Violation::ItemTooHeavy(_)]));
+
+        request.item = Some(Item {
+            dimensions: Some(Dimensions {
+                width: Meters(0.4),
+                height: Meters(0.4),
+                length: Meters(0.7),
+            }),
+            ..item("LESS_THAN_3KG")
+        });
+
+        let violations = request.validate(&market_info).unwrap_err().violations;
+        assert!(matches!(violations[..], [Violation::ItemTooLarge(_)]));
+
+        request.item = Some(item("LESS_THAN_3KG"));
+        request.schedule_at = Some(Utc::now() - Duration::hours(1));
+
+        let violations = request.validate(&market_info).unwrap_err().violations;
+        assert!(matches!(violations[..], [Violation::ScheduledInPast]));
+
+        request.item = Some(item("BETWEEN_50KG_AND_100KG"));
+        request.schedule_at = Some(Utc::now() - Duration::hours(1));
+
+        let violations = request.validate(&market_info).unwrap_err().violations;
+        assert!(matches!(
+            violations[..],
+            [Violation::ItemTooHeavy(_), Violation::ScheduledInPast]
+        ));
+
+        request.item = None;
+        request.schedule_at = Some(Utc::now() + Duration::days(31));
+
+        let violations = request.validate(&market_info).unwrap_err().violations;
+        assert!(matches!(violations[..], [Violation::ScheduledTooFarAhead]));
+
+        request.service = from_value::<ServiceType>(json!("TRUCK330")).unwrap();
+        request.schedule_at = None;
+
+        let violations = request.validate(&market_info).unwrap_err().violations;
+        assert!(matches!(violations[..], [Violation::ServiceUnavailable(_)]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "_client", feature = "market-ph"))]
+    fn validates_deserialized_configs() {
+        use crate::{ApiEnvironment, Config, PhilippineMarket};
+
+        let config = from_value::<Config<PhilippineMarket>>(json!({
+            "api_key": "pk_test_key",
+            "api_secret": "sk_test_secret",
+            "language": "en_PH",
+        }))
+        .unwrap();
+        assert_eq!(config.environment, ApiEnvironment::Sandbox);
+
+        let round_tripped = from_value::<Config<PhilippineMarket>>(to_value(&config).unwrap());
+        assert!(round_tripped.is_ok());
+
+        assert_eq!(
+            "sandbox".parse::<ApiEnvironment>().unwrap(),
+            ApiEnvironment::Sandbox
+        );
+        assert!("pk_test_key".parse::<ApiEnvironment>().is_err());
+        assert!(ApiEnvironment::from_key("sandbox").is_err());
+
+        for invalid in [
+            json!({
+                "api_key": "pk_test_key",
+                "api_secret": "sk_prod_secret",
+                "language": "en_PH",
+            }),
+            json!({
+                "api_key": "sandbox",
+                "api_secret": "sandbox",
+                "language": "en_PH",
+            }),
+            json!({
+                "api_key": "pk_test_key",
+                "api_secret": "sk_test_secret",
+                "language": "en_PH",
+                "environment": "production",
+            }),
+            json!({
+                "api_key": "pk_test_key",
+                "api_secret": "sk_test_secret",
+                "language": "en_PH",
+                "headers": { "X-Integration": "line\nbreak" },
+            }),
+            json!({
+                "api_key": "pk_test_key",
+                "api_secret": "sk_test_secret",
+                "language": "en_PH",
+                "user_agent": "checkout\r\n1.2.3",
+            }),
+            json!({
+                "api_key": "pk_test_key",
+                "api_secret": "sk_test_secret",
+                "language": "en_PH",
+                "partner": "partner\n",
+            }),
+        ] {
+            assert!(from_value::<Config<PhilippineMarket>>(invalid).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "market-ph")]
+    fn round_trips_philippine_services() {
+        use crate::{DynamicQuotationRequest, PhilippineServices as PS};
+
+        for service in [
+            PS::TenWheelTruck,
+            PS::Ld10WheelTruck,
+            PS::Motorcycle,
+            PS::Mpv,
+            PS::MpvIntercity,
+            PS::Pickup800KgIntercity,
+            PS::Sedan,
+            PS::SedanIntercity,
+            PS::Truck330,
+            PS::Truck550,
+            PS::Van,
+            PS::Van1000,
+            PS::VanIntercity,
+            PS::Custom("TRUCK_XL".to_string()),
+        ] {
+            assert_eq!(PS::from_key(service.key()), service);
+
+            let service_type = ServiceType::from(service.clone());
+            assert_eq!(to_value(&service_type).unwrap(), json!(service.key()));
+            assert_eq!(PS::from(&service_type), service);
+
+            assert_eq!(
+                from_value::<PS>(to_value(&service).unwrap()).unwrap(),
+                service
+            );
+        }
+
+        assert_eq!(PS::from_key("10WHEEL_TRUCK"), PS::TenWheelTruck);
+        assert_eq!(
+            from_value::<PS>(json!("TRUCK_XL")).unwrap(),
+            PS::Custom("TRUCK_XL".to_string())
+        );
+
+        let location = Location {
+            coordinates: Coordinates {
+                latitude: 14.535372967557564,
+                longitude: 120.98197538196277,
+            },
+            address: "Metro Manila".to_string(),
+        };
+
+        let request = QuotationRequest {
+            service: PS::Truck550,
+            pick_up_location: location.clone(),
+            stops: [location],
+            is_route_optimized: false,
+            item: None,
+            schedule_at: None,
+        };
+
+        assert_eq!(to_value(&request).unwrap()["service"], json!("TRUCK550"));
+        assert_eq!(
+            DynamicQuotationRequest::from(request).service,
+            from_value::<ServiceType>(json!("TRUCK550")).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "market-ph")]
+    fn round_trips_philippine_special_requests() {
+        use crate::{PhilippineSpecialRequests as PSR, SpecialRequest, SpecialRequestType};
+
+        for special_request in [
+            PSR::ThermalBag1,
+            PSR::Helper2,
+            PSR::CashOnDeliveryAutodeduct,
+            PSR::Other("DOOR_TO_DOOR".to_string()),
+        ] {
+            let special_request_type = SpecialRequestType::from(special_request.clone());
+            assert_eq!(
+                to_value(&special_request_type).unwrap(),
+                json!(special_request.key())
+            );
+            assert_eq!(PSR::from(special_request_type), special_request);
+        }
+
+        let special_request = from_value::<SpecialRequest<PSR>>(json!({
+            "description": "Door to door",
+            "special_request": "DOOR_TO_DOOR",
+            "surcharge": null,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            special_request.special_request,
+            PSR::Other("DOOR_TO_DOOR".to_string())
+        );
+        assert_eq!(
+            to_value(&special_request).unwrap()["special_request"],
+            json!("DOOR_TO_DOOR")
+        );
+    }
+
+    #[test]
+    fn builds_complete_requests() {
+        use crate::{DeliveryRequestBuilder, PaymentMethod, QuotationRequestBuilder};
+
+        let location = |address: &str| Location {
+            coordinates: Coordinates {
+                latitude: 14.535372967557564,
+                longitude: 120.98197538196277,
+            },
+            address: address.to_string(),
+        };
+
+        let quotation = QuotationRequestBuilder::new()
+            .with_stop(location("Mandaluyong"))
+            .with_service(from_value::<ServiceType>(json!("MOTORCYCLE")).unwrap())
+            .with_stop(location("Makati"))
+            .with_pick_up_location(location("Pasay"))
+            .with_route_optimized(true)
+            .build();
+
+        assert_eq!(quotation.pick_up_location.address, "Pasay");
+        assert_eq!(
+            quotation.stops.map(|stop| stop.address),
+            ["Mandaluyong", "Makati"]
+        );
+        assert!(quotation.is_route_optimized);
+
+        let quoted =
+            QuotedRequest::from_parts(QuotationId(1), StopId(2), [StopId(3), StopId(4)]).unwrap();
+
+        let person = |name: &str| PersonInfo::new(name, "+639000001024").unwrap();
+
+        let delivery = DeliveryRequestBuilder::new(quoted)
+            .with_recipient(person("Bob"))
+            .with_sender(person("Alice"))
+            .with_recipient(person("Carol"))
+            .with_payment_method(PaymentMethod::Cash)
+            .build();
+
+        assert_eq!(delivery.sender.name, "Alice");
+        assert_eq!(
+            delivery.recipients_info.map(|recipient| recipient.name),
+            ["Bob", "Carol"]
+        );
+        assert_eq!(delivery.cash_on_delivery, [None, None]);
+        assert_eq!(delivery.payment_method, PaymentMethod::Cash);
+    }
+}
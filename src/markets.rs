@@ -1,157 +1,304 @@
 use std::{
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     str::FromStr,
 };
 use thiserror::Error as ThisError;
 
+use phonenumber::country::Id as PhoneCountryId;
+use rusty_money::{
+    iso::{self, Currency},
+    Money,
+};
+
 use serde::{Deserialize, Serialize};
 
-use serde_with::{serde_as, DisplayFromStr};
+use serde_with::{serde_as, DeserializeFromStr, DisplayFromStr, SerializeDisplay};
+
+use cfg_if::cfg_if;
+
+use crate::{money::MoneyAmount, Coordinates, Location, MAX_RECIPIENT_STOPS};
+
+cfg_if! {
+    if #[cfg(not(any(feature = "market-ph", feature = "market-hk")))] {
+        compile_error!("At least one market feature (like [market-ph]) has to be enabled.");
+    }
+}
+
+mod dynamic;
+
+#[cfg(feature = "market-hk")]
+mod hong_kong;
+
+#[cfg(feature = "market-ph")]
+mod philippine_services;
+#[cfg(feature = "market-ph")]
+mod philippines;
+
+pub use dynamic::{DynamicLocale, DynamicMarket, InvalidDynamicLocale};
+
+#[cfg(feature = "market-hk")]
+pub use hong_kong::{HongKongLanguages, HongKongMarket, HongKongRegions, InvalidHongKongLanguage};
+
+#[cfg(feature = "market-ph")]
+pub use philippine_services::{PhilippineServices, PhilippineSpecialRequests};
+#[cfg(feature = "market-ph")]
+pub use philippines::{
+    InvalidPhilippineLanguage, PhilippineLanguages, PhilippineMarket, PhilippineRegions,
+};
 
 pub trait Market
 where
     <<Self as Market>::Languages as FromStr>::Err: Display,
 {
     type Languages: Language + Clone;
-    fn country() -> Country;
+    /// The service keys a [`QuotationRequest`](crate::QuotationRequest) for this market takes.
+    type Services: Clone + Debug + Into<ServiceType>;
+    /// The special requests `Lalamove::market_info` lists for this market's services.
+    type SpecialRequests: Clone + Debug + From<SpecialRequestType>;
+
+    const MAX_RECIPIENT_STOPS: usize = MAX_RECIPIENT_STOPS;
+
+    /// Derived from the market's default locale, which is also what decides the `Market` header, so
+    /// it's `None` for markets like [`DynamicMarket`] whose locale is only known at runtime.
+    fn country() -> Option<Country> {
+        Self::default_language().and_then(|language| language.country())
+    }
+
+    fn currency() -> Option<&'static Currency> {
+        Self::country().map(|country| country.currency())
+    }
+
+    fn default_language() -> Option<Self::Languages> {
+        Self::Languages::default_language()
+    }
 }
 
 pub trait Language: FromStr
 where
     Self::Err: Display,
 {
-    fn language_code(&self) -> &'static str;
-}
+    fn language_code(&self) -> &str;
+    fn country_code(&self) -> &str;
+    fn supported() -> Vec<Self>;
 
-#[derive(Debug, Clone)]
-pub struct PhilippineMarket;
+    fn country(&self) -> Option<Country> {
+        Country::from_country_code(self.country_code())
+    }
+
+    fn default_language() -> Option<Self> {
+        Self::supported().into_iter().next()
+    }
 
-impl Market for PhilippineMarket {
-    type Languages = PhilippineLanguages;
+    fn supported_codes() -> Vec<String> {
+        Self::supported()
+            .iter()
+            .map(|language| language.language_code().to_string())
+            .collect()
+    }
 
-    fn country() -> Country {
-        Country::Philippines
+    fn is_supported(language_code: &str) -> bool {
+        Self::from_str(language_code).is_ok()
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum PhilippineLanguages {
-    English,
+#[derive(Debug, Clone, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
+pub enum Country {
+    #[cfg(feature = "market-hk")]
+    HongKong,
+    #[cfg(feature = "market-ph")]
+    Philippines,
 }
 
-impl Language for PhilippineLanguages {
-    fn language_code(&self) -> &'static str {
-        use PhilippineLanguages as PL;
+impl Country {
+    pub const fn country_code(&self) -> &'static str {
+        use Country as C;
 
         match self {
-            PL::English => "en_PH",
+            #[cfg(feature = "market-hk")]
+            C::HongKong => "HK",
+            #[cfg(feature = "market-ph")]
+            C::Philippines => "PH",
         }
     }
-}
 
-#[derive(Debug, ThisError)]
-pub enum InvalidPhilippineLanguage {
-    #[error("Couldn't find a corresponding language for the language code.")]
-    NoLanguageCodeFound,
+    pub const fn phone_country_id(&self) -> PhoneCountryId {
+        use Country as C;
+
+        match self {
+            #[cfg(feature = "market-hk")]
+            C::HongKong => PhoneCountryId::HK,
+            #[cfg(feature = "market-ph")]
+            C::Philippines => PhoneCountryId::PH,
+        }
+    }
+
+    pub fn currency(&self) -> &'static Currency {
+        use Country as C;
+
+        match self {
+            #[cfg(feature = "market-hk")]
+            C::HongKong => iso::HKD,
+            #[cfg(feature = "market-ph")]
+            C::Philippines => iso::PHP,
+        }
+    }
+
+    pub fn from_country_code(country_code: &str) -> Option<Country> {
+        match country_code {
+            #[cfg(feature = "market-hk")]
+            "HK" => Some(Country::HongKong),
+            #[cfg(feature = "market-ph")]
+            "PH" => Some(Country::Philippines),
+            _ => None,
+        }
+    }
 }
 
-impl FromStr for PhilippineLanguages {
-    type Err = InvalidPhilippineLanguage;
+impl Display for Country {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.country_code())
+    }
+}
 
-    fn from_str(language_code: &str) -> Result<Self, Self::Err> {
-        use PhilippineLanguages as PS;
+impl FromStr for Country {
+    type Err = CountryError;
 
-        let language_code = language_code.to_lowercase();
+    fn from_str(country_code: &str) -> Result<Self, Self::Err> {
+        let country_code = country_code.trim().to_uppercase();
 
-        Ok(match &*language_code {
-            "en_ph" => PS::English,
-            _ => return Err(InvalidPhilippineLanguage::NoLanguageCodeFound),
-        })
+        Country::from_country_code(&country_code)
+            .ok_or(CountryError::UnknownCountryCode(country_code))
     }
 }
 
-pub enum Country {
-    Philippines,
+#[derive(Debug, ThisError)]
+pub enum CountryError {
+    #[error("Couldn't find a supported country with the country code '{0}'.")]
+    UnknownCountryCode(String),
 }
 
-impl Country {
-    pub const fn country_code(&self) -> &'static str {
-        use Country as C;
+#[derive(Debug, Clone, SerializeDisplay, DeserializeFromStr)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Region {
+    #[cfg(feature = "market-hk")]
+    HongKong(HongKongRegions),
+    #[cfg(feature = "market-ph")]
+    Philippines(PhilippineRegions),
+    Other(String),
+}
+
+impl Region {
+    pub fn country(&self) -> Option<Country> {
+        use Region as R;
 
         match self {
-            C::Philippines => "PH",
+            #[cfg(feature = "market-hk")]
+            R::HongKong(_) => Some(Country::HongKong),
+            #[cfg(feature = "market-ph")]
+            R::Philippines(_) => Some(Country::Philippines),
+            R::Other(locode) => Country::from_country_code(locode.split_whitespace().next()?),
+        }
+    }
+
+    pub fn service_area(&self) -> Option<ServiceArea> {
+        use Region as R;
+
+        match self {
+            #[cfg(feature = "market-hk")]
+            R::HongKong(region) => region.service_area(),
+            #[cfg(feature = "market-ph")]
+            R::Philippines(region) => region.service_area(),
+            R::Other(_) => None,
         }
     }
+
+    pub fn known() -> Vec<Region> {
+        let mut regions = Vec::new();
+
+        #[cfg(feature = "market-hk")]
+        regions.extend(HongKongRegions::known().into_iter().map(Region::HongKong));
+
+        #[cfg(feature = "market-ph")]
+        regions.extend(
+            PhilippineRegions::known()
+                .into_iter()
+                .map(Region::Philippines),
+        );
+
+        regions
+    }
+
+    pub fn from_coordinates(coordinates: &Coordinates) -> Option<Region> {
+        nearest(Region::known(), coordinates, Region::service_area)
+    }
 }
 
 #[derive(Debug, Clone)]
-pub enum Region {
-    Philippines(PhilippineRegions),
+pub struct ServiceArea {
+    pub center: Coordinates,
+    pub radius: Meters,
+}
+
+impl ServiceArea {
+    pub fn contains(&self, coordinates: &Coordinates) -> bool {
+        self.center.distance_to(coordinates).0 <= self.radius.0
+    }
+}
+
+fn nearest<T>(
+    candidates: impl IntoIterator<Item = T>,
+    coordinates: &Coordinates,
+    service_area: impl Fn(&T) -> Option<ServiceArea>,
+) -> Option<T> {
+    candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let area = service_area(&candidate)?;
+
+            area.contains(coordinates)
+                .then(|| (area.center.distance_to(coordinates).0, candidate))
+        })
+        .min_by(|(distance, _), (other_distance, _)| distance.total_cmp(other_distance))
+        .map(|(_, candidate)| candidate)
 }
 
 impl Display for Region {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
-        use PhilippineRegions as PR;
         use Region as R;
 
-        write!(
-            formatter,
-            "{}",
-            match self {
-                R::Philippines(region) => match region {
-                    PR::Cebu => "PH CEB",
-                    PR::Manila => "PH MNL",
-                    PR::Pampanga => "PH PAM",
-                },
-            }
-        )
+        match self {
+            #[cfg(feature = "market-hk")]
+            R::HongKong(region) => write!(formatter, "{region}"),
+            #[cfg(feature = "market-ph")]
+            R::Philippines(region) => write!(formatter, "{region}"),
+            R::Other(locode) => write!(formatter, "{locode}"),
+        }
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum PhilippineRegions {
-    Cebu,
-    Manila,
-    Pampanga,
-}
-
 impl FromStr for Region {
     type Err = RegionError;
 
     fn from_str(region: &str) -> Result<Region, RegionError> {
-        use PhilippineRegions as PR;
-        use Region as R;
-
-        let region = region.to_lowercase();
+        #[cfg(feature = "market-hk")]
+        if let Ok(region) = HongKongRegions::from_str(region) {
+            return Ok(Region::HongKong(region));
+        }
 
-        Ok(R::Philippines(match &*region {
-            "ph ceb" => PR::Cebu,
-            "ph mnl" => PR::Manila,
-            "ph pam" => PR::Pampanga,
-            _ => {
-                return Err(RegionError::InvalidString);
-            }
-        }))
-    }
-}
+        #[cfg(feature = "market-ph")]
+        if let Ok(region) = PhilippineRegions::from_str(region) {
+            return Ok(Region::Philippines(region));
+        }
 
-// impl Region {
-//     const fn location_code(&self) -> &'static str {
-//         use Region::*;
+        let region = region.trim();
 
-//         match self {
-//             Philippines(region) => {
-//                 use PhilippineRegions::*;
+        if region.is_empty() {
+            return Err(RegionError::InvalidString);
+        }
 
-//                 match region {
-//                     Cebu => "PH CEB",
-//                     Manila => "PH MNL",
-//                     Pampanga => "PH PAM",
-//                 }
-//             }
-//         }
-//     }
-// }
+        Ok(Region::Other(region.to_string()))
+    }
+}
 
 #[derive(Debug, ThisError)]
 pub enum RegionError {
@@ -160,29 +307,76 @@ pub enum RegionError {
 }
 
 #[derive(Deserialize, Debug, Serialize, Clone)]
-pub struct MarketInfo {
-    pub regions: Vec<RegionInfo>,
+pub struct MarketInfo<R = SpecialRequestType> {
+    pub regions: Vec<RegionInfo<R>>,
+}
+
+impl<R> MarketInfo<R> {
+    pub fn region_for(&self, location: &Location) -> Option<&RegionInfo<R>> {
+        nearest(&self.regions, &location.coordinates, |region| {
+            region.region.service_area()
+        })
+    }
+}
+
+impl<R> Display for MarketInfo<R> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        for region in &self.regions {
+            writeln!(formatter, "{region}")?;
+        }
+
+        Ok(())
+    }
 }
 
 #[serde_as]
 #[derive(Deserialize, Debug, Serialize, Clone)]
-pub struct RegionInfo {
+pub struct RegionInfo<R = SpecialRequestType> {
     #[serde_as(as = "DisplayFromStr")]
     pub region: Region,
-    pub services: Vec<Service>,
+    pub services: Vec<Service<R>>,
+}
+
+impl<R> Display for RegionInfo<R> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let services = self
+            .services
+            .iter()
+            .map(|service| service.service.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(
+            formatter,
+            "{}: {} service(s) ({services})",
+            self.region,
+            self.services.len()
+        )
+    }
 }
 
 #[derive(Deserialize, Debug, Serialize, Clone)]
-pub struct Service {
+pub struct Service<R = SpecialRequestType> {
     pub service: ServiceType,
     pub description: String,
     pub dimensions: Dimensions,
     pub load: Kilograms,
-    pub special_requests: Vec<SpecialRequest>,
+    pub special_requests: Vec<SpecialRequest<R>>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+impl<R> Display for Service<R> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            formatter,
+            "{} ({}): up to {}, {}",
+            self.service, self.description, self.load, self.dimensions
+        )
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(transparent)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ServiceType(String);
 
 impl Display for ServiceType {
@@ -191,25 +385,91 @@ impl Display for ServiceType {
     }
 }
 
+#[serde_as]
 #[derive(Deserialize, Debug, Serialize, Clone)]
-pub struct SpecialRequest {
+pub struct SpecialRequest<R = SpecialRequestType> {
     pub description: String,
-    pub special_request: SpecialRequestType,
+    pub special_request: R,
+    #[serde_as(as = "Option<MoneyAmount>")]
+    pub surcharge: Option<Money<'static, Currency>>,
 }
 
 #[derive(Deserialize, Debug, Serialize, Clone)]
 #[serde(transparent)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SpecialRequestType(String);
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Dimensions {
     pub width: Meters,
     pub height: Meters,
     pub length: Meters,
 }
 
+impl Display for Dimensions {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            formatter,
+            "{} × {} × {} m",
+            self.length.0, self.width.0, self.height.0
+        )
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Meters(pub f32);
 
+impl Meters {
+    const METERS_PER_FOOT: f32 = 0.3048;
+    const METERS_PER_INCH: f32 = 0.0254;
+
+    pub fn from_feet(feet: f32) -> Self {
+        Meters(feet * Self::METERS_PER_FOOT)
+    }
+
+    pub fn from_inches(inches: f32) -> Self {
+        Meters(inches * Self::METERS_PER_INCH)
+    }
+
+    pub fn to_feet(&self) -> f32 {
+        self.0 / Self::METERS_PER_FOOT
+    }
+
+    pub fn to_inches(&self) -> f32 {
+        self.0 / Self::METERS_PER_INCH
+    }
+}
+
+impl Display for Meters {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        if self.0 >= 1000.0 {
+            write!(formatter, "{:.1} km", self.0 / 1000.0)
+        } else {
+            write!(formatter, "{} m", self.0)
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Kilograms(pub f32);
+
+impl Kilograms {
+    const KILOGRAMS_PER_POUND: f32 = 0.453_592_37;
+
+    pub fn from_pounds(pounds: f32) -> Self {
+        Kilograms(pounds * Self::KILOGRAMS_PER_POUND)
+    }
+
+    pub fn to_pounds(&self) -> f32 {
+        self.0 / Self::KILOGRAMS_PER_POUND
+    }
+}
+
+impl Display for Kilograms {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{} kg", self.0)
+    }
+}
@@ -0,0 +1,99 @@
+use std::str::FromStr;
+use thiserror::Error as ThisError;
+
+use serde::{Deserialize, Serialize};
+
+use crate::markets::{Country, Language, Market, ServiceType, SpecialRequestType};
+
+#[derive(Debug, Clone)]
+pub struct DynamicMarket;
+
+impl Market for DynamicMarket {
+    type Languages = DynamicLocale;
+    type Services = ServiceType;
+    type SpecialRequests = SpecialRequestType;
+
+    fn country() -> Option<Country> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", try_from = "ApiDynamicLocale")]
+pub struct DynamicLocale {
+    country_code: String,
+    language_code: String,
+}
+
+impl DynamicLocale {
+    /// The country code ends up in the `Market` header, so it has to be a two letter ISO 3166 code.
+    pub fn new(
+        country_code: impl Into<String>,
+        language_code: impl Into<String>,
+    ) -> Result<Self, InvalidDynamicLocale> {
+        let country_code = country_code.into().trim().to_uppercase();
+
+        if country_code.len() != 2 || !country_code.bytes().all(|byte| byte.is_ascii_uppercase()) {
+            return Err(InvalidDynamicLocale::InvalidCountryCode(country_code));
+        }
+
+        Ok(DynamicLocale {
+            country_code,
+            language_code: language_code.into(),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiDynamicLocale {
+    country_code: String,
+    language_code: String,
+}
+
+impl TryFrom<ApiDynamicLocale> for DynamicLocale {
+    type Error = InvalidDynamicLocale;
+
+    fn try_from(locale: ApiDynamicLocale) -> Result<Self, Self::Error> {
+        DynamicLocale::new(locale.country_code, locale.language_code)
+    }
+}
+
+impl Language for DynamicLocale {
+    fn language_code(&self) -> &str {
+        &self.language_code
+    }
+
+    fn country_code(&self) -> &str {
+        &self.country_code
+    }
+
+    fn supported() -> Vec<Self> {
+        Vec::new()
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum InvalidDynamicLocale {
+    #[error("Couldn't find a country code in the language code.")]
+    NoCountryCodeFound,
+    #[error("'{0}' isn't a two letter country code.")]
+    InvalidCountryCode(String),
+}
+
+impl FromStr for DynamicLocale {
+    type Err = InvalidDynamicLocale;
+
+    fn from_str(language_code: &str) -> Result<Self, Self::Err> {
+        let language_code = language_code.trim();
+
+        let country_code = match language_code.split_once('_') {
+            Some((language, country_code)) if !language.is_empty() && !country_code.is_empty() => {
+                country_code
+            }
+            _ => return Err(InvalidDynamicLocale::NoCountryCodeFound),
+        };
+
+        DynamicLocale::new(country_code, language_code)
+    }
+}
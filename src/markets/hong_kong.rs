@@ -0,0 +1,138 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+use thiserror::Error as ThisError;
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::{
+    markets::{
+        Country, Language, Market, RegionError, ServiceArea, ServiceType, SpecialRequestType,
+    },
+    Coordinates, Meters,
+};
+
+#[derive(Debug, Clone)]
+pub struct HongKongMarket;
+
+impl Market for HongKongMarket {
+    type Languages = HongKongLanguages;
+    // There's no Hong Kong cities payload to generate enums from yet.
+    type Services = ServiceType;
+    type SpecialRequests = SpecialRequestType;
+}
+
+#[derive(Debug, Clone, SerializeDisplay, DeserializeFromStr)]
+pub enum HongKongLanguages {
+    English,
+    Chinese,
+}
+
+impl Language for HongKongLanguages {
+    fn language_code(&self) -> &str {
+        use HongKongLanguages as HL;
+
+        match self {
+            HL::English => "en_HK",
+            HL::Chinese => "zh_HK",
+        }
+    }
+
+    fn country_code(&self) -> &str {
+        Country::HongKong.country_code()
+    }
+
+    fn supported() -> Vec<Self> {
+        use HongKongLanguages as HL;
+
+        vec![HL::English, HL::Chinese]
+    }
+}
+
+impl Display for HongKongLanguages {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.language_code())
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum InvalidHongKongLanguage {
+    #[error("Couldn't find a corresponding language for the language code.")]
+    NoLanguageCodeFound,
+}
+
+impl FromStr for HongKongLanguages {
+    type Err = InvalidHongKongLanguage;
+
+    fn from_str(language_code: &str) -> Result<Self, Self::Err> {
+        use HongKongLanguages as HL;
+
+        let language_code = language_code.to_lowercase();
+
+        Ok(match &*language_code {
+            "en_hk" => HL::English,
+            "zh_hk" => HL::Chinese,
+            _ => return Err(InvalidHongKongLanguage::NoLanguageCodeFound),
+        })
+    }
+}
+
+#[derive(Debug, Clone, SerializeDisplay, DeserializeFromStr)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum HongKongRegions {
+    HongKong,
+}
+
+impl HongKongRegions {
+    pub fn known() -> Vec<HongKongRegions> {
+        vec![HongKongRegions::HongKong]
+    }
+
+    pub fn service_area(&self) -> Option<ServiceArea> {
+        use HongKongRegions as HR;
+
+        let (latitude, longitude, radius) = match self {
+            HR::HongKong => (22.3193, 114.1694, 50_000.0),
+        };
+
+        Some(ServiceArea {
+            center: Coordinates {
+                latitude,
+                longitude,
+            },
+            radius: Meters(radius),
+        })
+    }
+}
+
+impl Display for HongKongRegions {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        use HongKongRegions as HR;
+
+        write!(
+            formatter,
+            "{}",
+            match self {
+                HR::HongKong => "HK HKG",
+            }
+        )
+    }
+}
+
+impl FromStr for HongKongRegions {
+    type Err = RegionError;
+
+    fn from_str(region: &str) -> Result<Self, Self::Err> {
+        use HongKongRegions as HR;
+
+        let region = region.to_lowercase();
+
+        Ok(match &*region {
+            "hk hkg" => HR::HongKong,
+            _ => {
+                return Err(RegionError::InvalidString);
+            }
+        })
+    }
+}
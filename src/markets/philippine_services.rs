@@ -0,0 +1,287 @@
+// This file was generated by the `parsing` crate from `parsing/market_info.json`.
+
+use std::{
+    convert::Infallible,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::markets::{ServiceType, SpecialRequestType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub enum PhilippineServices {
+    TenWheelTruck,
+    Ld10WheelTruck,
+    Motorcycle,
+    Mpv,
+    MpvIntercity,
+    Pickup800KgIntercity,
+    Sedan,
+    SedanIntercity,
+    Truck330,
+    Truck550,
+    Van,
+    Van1000,
+    VanIntercity,
+    Custom(String),
+}
+
+impl PhilippineServices {
+    pub fn from_key(key: &str) -> Self {
+        use PhilippineServices as PS;
+
+        match key {
+            "10WHEEL_TRUCK" => PS::TenWheelTruck,
+            "LD_10WHEEL_TRUCK" => PS::Ld10WheelTruck,
+            "MOTORCYCLE" => PS::Motorcycle,
+            "MPV" => PS::Mpv,
+            "MPV_INTERCITY" => PS::MpvIntercity,
+            "PICKUP_800KG_INTERCITY" => PS::Pickup800KgIntercity,
+            "SEDAN" => PS::Sedan,
+            "SEDAN_INTERCITY" => PS::SedanIntercity,
+            "TRUCK330" => PS::Truck330,
+            "TRUCK550" => PS::Truck550,
+            "VAN" => PS::Van,
+            "VAN1000" => PS::Van1000,
+            "VAN_INTERCITY" => PS::VanIntercity,
+            _ => PS::Custom(key.to_string()),
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        use PhilippineServices as PS;
+
+        match self {
+            PS::TenWheelTruck => "10WHEEL_TRUCK",
+            PS::Ld10WheelTruck => "LD_10WHEEL_TRUCK",
+            PS::Motorcycle => "MOTORCYCLE",
+            PS::Mpv => "MPV",
+            PS::MpvIntercity => "MPV_INTERCITY",
+            PS::Pickup800KgIntercity => "PICKUP_800KG_INTERCITY",
+            PS::Sedan => "SEDAN",
+            PS::SedanIntercity => "SEDAN_INTERCITY",
+            PS::Truck330 => "TRUCK330",
+            PS::Truck550 => "TRUCK550",
+            PS::Van => "VAN",
+            PS::Van1000 => "VAN1000",
+            PS::VanIntercity => "VAN_INTERCITY",
+            PS::Custom(key) => key,
+        }
+    }
+}
+
+impl Display for PhilippineServices {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.key())
+    }
+}
+
+impl FromStr for PhilippineServices {
+    type Err = Infallible;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        Ok(PhilippineServices::from_key(key))
+    }
+}
+
+impl From<PhilippineServices> for ServiceType {
+    fn from(value: PhilippineServices) -> Self {
+        ServiceType(value.key().to_string())
+    }
+}
+
+impl From<ServiceType> for PhilippineServices {
+    fn from(value: ServiceType) -> Self {
+        PhilippineServices::from_key(&value.0)
+    }
+}
+
+impl From<&ServiceType> for PhilippineServices {
+    fn from(value: &ServiceType) -> Self {
+        PhilippineServices::from_key(&value.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub enum PhilippineSpecialRequests {
+    CashOnDelivery,
+    CashOnDeliveryAutodeduct,
+    DocumentProcessing,
+    Helper,
+    Helper2,
+    LoadingService,
+    MovingService1,
+    MovingService2,
+    MovingService3,
+    ParentHelper,
+    PurchaseService1,
+    PurchaseService2,
+    PurchaseService3,
+    PurchaseService4,
+    QueueingService,
+    ThermalBag1,
+    Other(String),
+}
+
+impl PhilippineSpecialRequests {
+    pub fn from_key(key: &str) -> Self {
+        use PhilippineSpecialRequests as PSR;
+
+        match key {
+            "CASH_ON_DELIVERY" => PSR::CashOnDelivery,
+            "CASH_ON_DELIVERY_AUTODEDUCT" => PSR::CashOnDeliveryAutodeduct,
+            "DOCUMENT_PROCESSING" => PSR::DocumentProcessing,
+            "HELPER" => PSR::Helper,
+            "HELPER_2" => PSR::Helper2,
+            "LOADING_SERVICE" => PSR::LoadingService,
+            "MOVING_SERVICE_1" => PSR::MovingService1,
+            "MOVING_SERVICE_2" => PSR::MovingService2,
+            "MOVING_SERVICE_3" => PSR::MovingService3,
+            "PARENT_HELPER" => PSR::ParentHelper,
+            "PURCHASE_SERVICE_1" => PSR::PurchaseService1,
+            "PURCHASE_SERVICE_2" => PSR::PurchaseService2,
+            "PURCHASE_SERVICE_3" => PSR::PurchaseService3,
+            "PURCHASE_SERVICE_4" => PSR::PurchaseService4,
+            "QUEUEING_SERVICE" => PSR::QueueingService,
+            "THERMAL_BAG_1" => PSR::ThermalBag1,
+            _ => PSR::Other(key.to_string()),
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        use PhilippineSpecialRequests as PSR;
+
+        match self {
+            PSR::CashOnDelivery => "CASH_ON_DELIVERY",
+            PSR::CashOnDeliveryAutodeduct => "CASH_ON_DELIVERY_AUTODEDUCT",
+            PSR::DocumentProcessing => "DOCUMENT_PROCESSING",
+            PSR::Helper => "HELPER",
+            PSR::Helper2 => "HELPER_2",
+            PSR::LoadingService => "LOADING_SERVICE",
+            PSR::MovingService1 => "MOVING_SERVICE_1",
+            PSR::MovingService2 => "MOVING_SERVICE_2",
+            PSR::MovingService3 => "MOVING_SERVICE_3",
+            PSR::ParentHelper => "PARENT_HELPER",
+            PSR::PurchaseService1 => "PURCHASE_SERVICE_1",
+            PSR::PurchaseService2 => "PURCHASE_SERVICE_2",
+            PSR::PurchaseService3 => "PURCHASE_SERVICE_3",
+            PSR::PurchaseService4 => "PURCHASE_SERVICE_4",
+            PSR::QueueingService => "QUEUEING_SERVICE",
+            PSR::ThermalBag1 => "THERMAL_BAG_1",
+            PSR::Other(key) => key,
+        }
+    }
+}
+
+impl Display for PhilippineSpecialRequests {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.key())
+    }
+}
+
+impl FromStr for PhilippineSpecialRequests {
+    type Err = Infallible;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        Ok(PhilippineSpecialRequests::from_key(key))
+    }
+}
+
+impl From<PhilippineSpecialRequests> for SpecialRequestType {
+    fn from(value: PhilippineSpecialRequests) -> Self {
+        SpecialRequestType(value.key().to_string())
+    }
+}
+
+impl From<SpecialRequestType> for PhilippineSpecialRequests {
+    fn from(value: SpecialRequestType) -> Self {
+        PhilippineSpecialRequests::from_key(&value.0)
+    }
+}
+
+impl From<&SpecialRequestType> for PhilippineSpecialRequests {
+    fn from(value: &SpecialRequestType) -> Self {
+        PhilippineSpecialRequests::from_key(&value.0)
+    }
+}
+
+impl PhilippineServices {
+    pub fn special_requests(&self) -> Vec<PhilippineSpecialRequests> {
+        use PhilippineServices as PS;
+        use PhilippineSpecialRequests as PSR;
+
+        match self {
+            PS::TenWheelTruck => vec![
+                PSR::DocumentProcessing,
+                PSR::Helper,
+                PSR::Helper2,
+                PSR::ParentHelper,
+            ],
+            PS::Ld10WheelTruck => vec![
+                PSR::DocumentProcessing,
+                PSR::Helper,
+                PSR::Helper2,
+                PSR::ParentHelper,
+            ],
+            PS::Motorcycle => vec![
+                PSR::CashOnDelivery,
+                PSR::CashOnDeliveryAutodeduct,
+                PSR::PurchaseService1,
+                PSR::PurchaseService2,
+                PSR::PurchaseService3,
+                PSR::PurchaseService4,
+                PSR::QueueingService,
+                PSR::ThermalBag1,
+            ],
+            PS::Mpv => vec![
+                PSR::DocumentProcessing,
+                PSR::LoadingService,
+                PSR::MovingService1,
+                PSR::MovingService2,
+                PSR::MovingService3,
+            ],
+            PS::MpvIntercity => vec![],
+            PS::Pickup800KgIntercity => vec![],
+            PS::Sedan => vec![
+                PSR::DocumentProcessing,
+                PSR::LoadingService,
+                PSR::PurchaseService1,
+                PSR::PurchaseService2,
+                PSR::PurchaseService3,
+                PSR::PurchaseService4,
+            ],
+            PS::SedanIntercity => vec![],
+            PS::Truck330 => vec![
+                PSR::DocumentProcessing,
+                PSR::LoadingService,
+                PSR::MovingService1,
+                PSR::MovingService2,
+                PSR::MovingService3,
+            ],
+            PS::Truck550 => vec![
+                PSR::DocumentProcessing,
+                PSR::MovingService1,
+                PSR::MovingService2,
+                PSR::MovingService3,
+            ],
+            PS::Van => vec![
+                PSR::DocumentProcessing,
+                PSR::LoadingService,
+                PSR::MovingService1,
+                PSR::MovingService2,
+                PSR::MovingService3,
+            ],
+            PS::Van1000 => vec![
+                PSR::DocumentProcessing,
+                PSR::LoadingService,
+                PSR::MovingService1,
+                PSR::MovingService2,
+                PSR::MovingService3,
+            ],
+            PS::VanIntercity => vec![],
+            PS::Custom(_) => Vec::new(),
+        }
+    }
+}
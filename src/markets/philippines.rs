@@ -0,0 +1,169 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+use thiserror::Error as ThisError;
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::{
+    markets::{
+        Country, Language, Market, PhilippineServices, PhilippineSpecialRequests, RegionError,
+        ServiceArea,
+    },
+    Coordinates, Meters,
+};
+
+#[derive(Debug, Clone)]
+pub struct PhilippineMarket;
+
+impl Market for PhilippineMarket {
+    type Languages = PhilippineLanguages;
+    type Services = PhilippineServices;
+    type SpecialRequests = PhilippineSpecialRequests;
+}
+
+#[derive(Debug, Clone, SerializeDisplay, DeserializeFromStr)]
+pub enum PhilippineLanguages {
+    English,
+}
+
+impl Language for PhilippineLanguages {
+    fn language_code(&self) -> &str {
+        use PhilippineLanguages as PL;
+
+        match self {
+            PL::English => "en_PH",
+        }
+    }
+
+    fn country_code(&self) -> &str {
+        Country::Philippines.country_code()
+    }
+
+    fn supported() -> Vec<Self> {
+        use PhilippineLanguages as PL;
+
+        vec![PL::English]
+    }
+}
+
+impl Display for PhilippineLanguages {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.language_code())
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum InvalidPhilippineLanguage {
+    #[error("Couldn't find a corresponding language for the language code.")]
+    NoLanguageCodeFound,
+}
+
+impl FromStr for PhilippineLanguages {
+    type Err = InvalidPhilippineLanguage;
+
+    fn from_str(language_code: &str) -> Result<Self, Self::Err> {
+        use PhilippineLanguages as PS;
+
+        let language_code = language_code.to_lowercase();
+
+        Ok(match &*language_code {
+            "en_ph" => PS::English,
+            _ => return Err(InvalidPhilippineLanguage::NoLanguageCodeFound),
+        })
+    }
+}
+
+#[derive(Debug, Clone, SerializeDisplay, DeserializeFromStr)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum PhilippineRegions {
+    Bacolod,
+    CagayanDeOro,
+    Cebu,
+    Davao,
+    Iloilo,
+    Manila,
+    Pampanga,
+}
+
+impl PhilippineRegions {
+    pub fn known() -> Vec<PhilippineRegions> {
+        use PhilippineRegions as PR;
+
+        vec![
+            PR::Bacolod,
+            PR::CagayanDeOro,
+            PR::Cebu,
+            PR::Davao,
+            PR::Iloilo,
+            PR::Manila,
+            PR::Pampanga,
+        ]
+    }
+
+    pub fn service_area(&self) -> Option<ServiceArea> {
+        use PhilippineRegions as PR;
+
+        let (latitude, longitude, radius) = match self {
+            PR::Bacolod => (10.6765, 122.9509, 30_000.0),
+            PR::CagayanDeOro => (8.4542, 124.6319, 30_000.0),
+            PR::Cebu => (10.3157, 123.8854, 40_000.0),
+            PR::Davao => (7.1907, 125.4553, 40_000.0),
+            PR::Iloilo => (10.7202, 122.5621, 30_000.0),
+            PR::Manila => (14.5995, 120.9842, 60_000.0),
+            PR::Pampanga => (15.0794, 120.6200, 40_000.0),
+        };
+
+        Some(ServiceArea {
+            center: Coordinates {
+                latitude,
+                longitude,
+            },
+            radius: Meters(radius),
+        })
+    }
+}
+
+impl Display for PhilippineRegions {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        use PhilippineRegions as PR;
+
+        write!(
+            formatter,
+            "{}",
+            match self {
+                PR::Bacolod => "PH BCD",
+                PR::CagayanDeOro => "PH CGY",
+                PR::Cebu => "PH CEB",
+                PR::Davao => "PH DVO",
+                PR::Iloilo => "PH ILO",
+                PR::Manila => "PH MNL",
+                PR::Pampanga => "PH PAM",
+            }
+        )
+    }
+}
+
+impl FromStr for PhilippineRegions {
+    type Err = RegionError;
+
+    fn from_str(region: &str) -> Result<Self, Self::Err> {
+        use PhilippineRegions as PR;
+
+        let region = region.trim();
+
+        Ok(match &*region.to_lowercase() {
+            "ph bcd" => PR::Bacolod,
+            "ph cgy" => PR::CagayanDeOro,
+            "ph ceb" => PR::Cebu,
+            "ph dvo" => PR::Davao,
+            "ph ilo" => PR::Iloilo,
+            "ph mnl" => PR::Manila,
+            "ph pam" => PR::Pampanga,
+            _ => {
+                return Err(RegionError::InvalidString);
+            }
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use rusty_money::{
+    iso::{self, Currency},
+    Money,
+};
+
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+pub(crate) struct MoneyAmount;
+
+#[derive(Serialize, Deserialize)]
+struct MoneyAmountDef {
+    amount: String,
+    currency: String,
+}
+
+impl SerializeAs<Money<'static, Currency>> for MoneyAmount {
+    fn serialize_as<S: Serializer>(
+        source: &Money<'static, Currency>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        MoneyAmountDef {
+            amount: source.amount().to_string(),
+            currency: source.currency().iso_alpha_code.to_string(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Money<'static, Currency>> for MoneyAmount {
+    fn deserialize_as<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Money<'static, Currency>, D::Error> {
+        let money = MoneyAmountDef::deserialize(deserializer)?;
+
+        let currency = iso::find(&money.currency)
+            .ok_or_else(|| DeError::custom(format!("unknown currency '{}'", money.currency)))?;
+        let amount = Decimal::from_str(&money.amount).map_err(DeError::custom)?;
+
+        Ok(Money::from_decimal(amount, currency))
+    }
+}
@@ -0,0 +1,28 @@
+use phonenumber::{parse, Mode, ParseError, PhoneNumber};
+
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+pub(crate) struct E164;
+
+impl SerializeAs<PhoneNumber> for E164 {
+    fn serialize_as<S: Serializer>(source: &PhoneNumber, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&e164(source))
+    }
+}
+
+impl<'de> DeserializeAs<'de, PhoneNumber> for E164 {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<PhoneNumber, D::Error> {
+        let phone_number = String::deserialize(deserializer)?;
+
+        parse(None, phone_number).map_err(DeError::custom)
+    }
+}
+
+pub(crate) fn e164(phone_number: &PhoneNumber) -> String {
+    phone_number.format().mode(Mode::E164).to_string()
+}
+
+pub(crate) fn normalize(phone_number: &PhoneNumber) -> Result<PhoneNumber, ParseError> {
+    parse(None, e164(phone_number))
+}
@@ -0,0 +1,43 @@
+#[cfg(feature = "qr-png")]
+use std::io::Cursor;
+
+#[cfg(feature = "qr-png")]
+use image::{ImageError, ImageOutputFormat, Luma};
+use qrcode::{render::svg, types::QrError, QrCode};
+#[cfg(feature = "qr-png")]
+use thiserror::Error as ThisError;
+
+use crate::Delivery;
+
+const MIN_DIMENSION: u32 = 200;
+
+impl Delivery {
+    pub fn share_link_qr(&self) -> Result<String, QrError> {
+        Ok(QrCode::new(self.share_link.to_string())?
+            .render::<svg::Color>()
+            .min_dimensions(MIN_DIMENSION, MIN_DIMENSION)
+            .build())
+    }
+
+    #[cfg(feature = "qr-png")]
+    pub fn share_link_qr_png(&self) -> Result<Vec<u8>, QrPngError> {
+        let image = QrCode::new(self.share_link.to_string())?
+            .render::<Luma<u8>>()
+            .min_dimensions(MIN_DIMENSION, MIN_DIMENSION)
+            .build();
+
+        let mut png = Cursor::new(Vec::new());
+        image.write_to(&mut png, ImageOutputFormat::Png)?;
+
+        Ok(png.into_inner())
+    }
+}
+
+#[cfg(feature = "qr-png")]
+#[derive(Debug, ThisError)]
+pub enum QrPngError {
+    #[error(transparent)]
+    QrError(#[from] QrError),
+    #[error(transparent)]
+    ImageError(#[from] ImageError),
+}
@@ -0,0 +1,31 @@
+#[cfg(any(feature = "_client", feature = "webhook-test"))]
+use hex::encode;
+use hmac::{Hmac, Mac};
+use http::Method;
+use sha2::Sha256;
+
+/// Lalamove signs requests and the webhooks it sends back the same way, so both go through here.
+pub(crate) fn mac(
+    api_secret: &str,
+    time: i64,
+    method: &Method,
+    path: &str,
+    body: &str,
+) -> Hmac<Sha256> {
+    let raw_signature = format!("{time}\r\n{method}\r\n{path}\r\n\r\n{body}");
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(api_secret.as_bytes())
+        .expect("Failed to interpret the API SECRET as bytes!");
+    mac.update(raw_signature.as_bytes());
+
+    mac
+}
+
+#[cfg(any(feature = "_client", feature = "webhook-test"))]
+pub fn sign(api_secret: &str, time: i64, method: &Method, path: &str, body: &str) -> String {
+    encode(
+        mac(api_secret, time, method, path, body)
+            .finalize()
+            .into_bytes(),
+    )
+}
@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+
+use crate::{DeliveryId, DeliveryStatus, OrderDetails, WebhookEvent};
+
+/// Collects what is known about one delivery so it can be read back in chronological order.
+#[derive(Debug, Clone)]
+pub struct OrderTimeline {
+    delivery: DeliveryId,
+    events: Vec<OrderEvent>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderEvent {
+    pub at: DateTime<Utc>,
+    pub source: EventSource,
+    pub kind: OrderEventKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventSource {
+    Webhook,
+    Polling,
+    OrderDetails,
+}
+
+#[derive(Debug, Clone)]
+pub enum OrderEventKind {
+    Created,
+    DriverAssigned,
+    StatusChanged(DeliveryStatus),
+    Webhook(WebhookEvent),
+}
+
+impl OrderTimeline {
+    pub fn new(delivery: DeliveryId) -> Self {
+        OrderTimeline {
+            delivery,
+            events: Vec::new(),
+        }
+    }
+
+    /// Webhooks for other deliveries, and ones without a timestamp, are ignored.
+    pub fn record_webhook(&mut self, event: WebhookEvent) {
+        if event.delivery_id() != Some(&self.delivery) {
+            return;
+        }
+
+        let Some(at) = event.updated_at() else {
+            return;
+        };
+
+        self.push(at, EventSource::Webhook, OrderEventKind::Webhook(event));
+    }
+
+    pub fn record_polled_status(&mut self, status: DeliveryStatus, at: DateTime<Utc>) {
+        self.push(
+            at,
+            EventSource::Polling,
+            OrderEventKind::StatusChanged(status),
+        );
+    }
+
+    pub fn record_details(&mut self, details: &OrderDetails) {
+        use EventSource::OrderDetails as OD;
+        use OrderEventKind as OEK;
+
+        if details.id != self.delivery {
+            return;
+        }
+
+        if let Some(created_at) = details.created_at {
+            self.push(created_at, OD, OEK::Created);
+        }
+
+        if let Some(driver_assigned_at) = details.driver_assigned_at {
+            self.push(driver_assigned_at, OD, OEK::DriverAssigned);
+        }
+
+        if let Some(updated_at) = details.updated_at {
+            self.push(updated_at, OD, OEK::StatusChanged(details.status.clone()));
+        }
+    }
+
+    /// Events that happened at the same time keep the order they were recorded in.
+    pub fn timeline(&self) -> Vec<OrderEvent> {
+        let mut events = self.events.clone();
+        events.sort_by_key(|event| event.at);
+        events
+    }
+
+    fn push(&mut self, at: DateTime<Utc>, source: EventSource, kind: OrderEventKind) {
+        self.events.push(OrderEvent { at, source, kind });
+    }
+}
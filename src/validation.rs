@@ -0,0 +1,312 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::once,
+    slice::from_ref,
+};
+
+use thiserror::Error as ThisError;
+
+use chrono::{DateTime, Duration, Utc};
+use phonenumber::{is_valid, PhoneNumber};
+use rusty_money::{iso::Currency, Money};
+
+use crate::{
+    valid_recipient_stop_count, Assert, Coordinates, Country, DeliveryRequest, Dimensions, IsTrue,
+    Item, Kilograms, Location, MarketInfo, PersonInfo, QuotationRequest, Service, ServiceType,
+};
+
+const MAX_SCHEDULE_AHEAD_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopPosition {
+    PickUp,
+    DropOff(usize),
+}
+
+impl Display for StopPosition {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        use StopPosition as SP;
+
+        match self {
+            SP::PickUp => write!(formatter, "the pick up stop"),
+            SP::DropOff(index) => write!(formatter, "drop off stop #{}", index + 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, ThisError)]
+pub enum Violation {
+    #[error("The service '{0}' isn't offered in any region of the market.")]
+    ServiceUnavailable(ServiceType),
+    #[error("The item is heavier than the service '{0}' can carry.")]
+    ItemTooHeavy(ServiceType),
+    #[error("The item is too large to fit in the service '{0}'.")]
+    ItemTooLarge(ServiceType),
+    #[error("The scheduled pick up time has already passed.")]
+    ScheduledInPast,
+    #[error("The scheduled pick up time is more than 30 days away.")]
+    ScheduledTooFarAhead,
+    #[error("The coordinates of {0} are out of range.")]
+    InvalidCoordinates(StopPosition),
+    #[error("The address of {0} is empty.")]
+    EmptyAddress(StopPosition),
+    #[error("The contact name for {0} is empty.")]
+    EmptyName(StopPosition),
+    #[error("The phone number for {0} isn't a valid phone number.")]
+    InvalidPhoneNumber(StopPosition),
+    #[error("The phone number for {0} doesn't belong to a country in the market.")]
+    PhoneNumberOutsideMarket(StopPosition),
+    #[error("The cash on delivery amount for {0} isn't positive.")]
+    NonPositiveCashOnDelivery(StopPosition),
+    #[error("The cash on delivery amount for {0} isn't in a currency used by the market.")]
+    CashOnDeliveryCurrencyMismatch(StopPosition),
+}
+
+#[derive(Debug, Clone, ThisError)]
+#[error("The request failed {} validation check(s).", .violations.len())]
+pub struct ValidationReport {
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationReport {
+    fn into_result(self) -> Result<(), ValidationReport> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<const RECIPIENT_STOP_COUNT: usize, S: Clone + Into<ServiceType>>
+    QuotationRequest<RECIPIENT_STOP_COUNT, S>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    pub fn validate<R>(&self, market_info: &MarketInfo<R>) -> Result<(), ValidationReport> {
+        let mut violations = Vec::new();
+
+        let service_type = self.service.clone().into();
+
+        let services = market_info
+            .regions
+            .iter()
+            .flat_map(|region| &region.services)
+            .filter(|service| service.service == service_type)
+            .collect::<Vec<_>>();
+
+        if services.is_empty() {
+            violations.push(Violation::ServiceUnavailable(service_type));
+        } else if let Some(item) = &self.item {
+            check_item(&service_type, item, &services, &mut violations);
+        }
+
+        if let Some(schedule_at) = &self.schedule_at {
+            check_schedule(schedule_at, &mut violations);
+        }
+
+        let locations = once((StopPosition::PickUp, &self.pick_up_location)).chain(
+            self.stops
+                .iter()
+                .enumerate()
+                .map(|(index, location)| (StopPosition::DropOff(index), location)),
+        );
+
+        for (position, location) in locations {
+            check_location(position, location, &mut violations);
+        }
+
+        ValidationReport { violations }.into_result()
+    }
+}
+
+impl<const RECIPIENT_STOP_COUNT: usize> DeliveryRequest<RECIPIENT_STOP_COUNT>
+where
+    Assert<{ valid_recipient_stop_count(RECIPIENT_STOP_COUNT) }>: IsTrue,
+{
+    pub fn validate<R>(&self, market_info: &MarketInfo<R>) -> Result<(), ValidationReport> {
+        let mut violations = Vec::new();
+
+        let countries = market_info
+            .regions
+            .iter()
+            .filter_map(|region| region.region.country())
+            .collect::<Vec<_>>();
+
+        for (position, person) in self.people() {
+            check_person(position, person, &countries, &mut violations);
+        }
+
+        for (index, amount) in self.cash_on_delivery.iter().enumerate() {
+            if let Some(amount) = amount {
+                check_cash_on_delivery(
+                    StopPosition::DropOff(index),
+                    amount,
+                    &countries,
+                    &mut violations,
+                );
+            }
+        }
+
+        ValidationReport { violations }.into_result()
+    }
+
+    pub fn validate_phone_numbers(&self, country: &Country) -> Result<(), ValidationReport> {
+        let mut violations = Vec::new();
+
+        for (position, person) in self.people() {
+            check_phone_number(
+                position,
+                &person.phone_number,
+                from_ref(country),
+                &mut violations,
+            );
+        }
+
+        ValidationReport { violations }.into_result()
+    }
+
+    fn people(&self) -> impl Iterator<Item = (StopPosition, &PersonInfo)> {
+        once((StopPosition::PickUp, &self.sender)).chain(
+            self.recipients_info
+                .iter()
+                .enumerate()
+                .map(|(index, person)| (StopPosition::DropOff(index), person)),
+        )
+    }
+}
+
+fn check_location(position: StopPosition, location: &Location, violations: &mut Vec<Violation>) {
+    if !valid_coordinates(&location.coordinates) {
+        violations.push(Violation::InvalidCoordinates(position));
+    }
+
+    if location.address.trim().is_empty() {
+        violations.push(Violation::EmptyAddress(position));
+    }
+}
+
+fn check_item<R>(
+    service_type: &ServiceType,
+    item: &Item,
+    services: &[&Service<R>],
+    violations: &mut Vec<Violation>,
+) {
+    if let Some(Kilograms(minimum)) = minimum_weight(item) {
+        if services.iter().all(|service| minimum > service.load.0) {
+            violations.push(Violation::ItemTooHeavy(service_type.clone()));
+        }
+    }
+
+    if let Some(dimensions) = &item.dimensions {
+        if !services
+            .iter()
+            .any(|service| fits(dimensions, &service.dimensions))
+        {
+            violations.push(Violation::ItemTooLarge(service_type.clone()));
+        }
+    }
+}
+
+fn check_schedule(schedule_at: &DateTime<Utc>, violations: &mut Vec<Violation>) {
+    let now = Utc::now();
+
+    if *schedule_at < now {
+        violations.push(Violation::ScheduledInPast);
+    } else if *schedule_at > now + Duration::days(MAX_SCHEDULE_AHEAD_DAYS) {
+        violations.push(Violation::ScheduledTooFarAhead);
+    }
+}
+
+fn check_person(
+    position: StopPosition,
+    person: &PersonInfo,
+    countries: &[Country],
+    violations: &mut Vec<Violation>,
+) {
+    if person.name.trim().is_empty() {
+        violations.push(Violation::EmptyName(position));
+    }
+
+    check_phone_number(position, &person.phone_number, countries, violations);
+}
+
+fn check_phone_number(
+    position: StopPosition,
+    phone_number: &PhoneNumber,
+    countries: &[Country],
+    violations: &mut Vec<Violation>,
+) {
+    if !is_valid(phone_number) {
+        violations.push(Violation::InvalidPhoneNumber(position));
+    } else if !countries
+        .iter()
+        .any(|country| in_country(phone_number, country))
+    {
+        violations.push(Violation::PhoneNumberOutsideMarket(position));
+    }
+}
+
+fn check_cash_on_delivery(
+    position: StopPosition,
+    amount: &Money<'static, Currency>,
+    countries: &[Country],
+    violations: &mut Vec<Violation>,
+) {
+    if !amount.is_positive() {
+        violations.push(Violation::NonPositiveCashOnDelivery(position));
+    }
+
+    if !countries
+        .iter()
+        .any(|country| country.currency() == amount.currency())
+    {
+        violations.push(Violation::CashOnDeliveryCurrencyMismatch(position));
+    }
+}
+
+fn valid_coordinates(coordinates: &Coordinates) -> bool {
+    let Coordinates {
+        latitude,
+        longitude,
+    } = *coordinates;
+
+    latitude.is_finite()
+        && longitude.is_finite()
+        && (-90.0..=90.0).contains(&latitude)
+        && (-180.0..=180.0).contains(&longitude)
+}
+
+/// Lalamove describes weights as ranges like `LESS_THAN_3KG` or `BETWEEN_3KG_AND_10KG`, so only
+/// their lower bound says anything about whether the item can fit.
+fn minimum_weight(item: &Item) -> Option<Kilograms> {
+    let weight = item.weight.0.to_uppercase();
+
+    if weight.starts_with("LESS_THAN") {
+        return Some(Kilograms(0.0));
+    }
+
+    weight
+        .split('_')
+        .find_map(|part| part.trim_end_matches("KG").parse::<f32>().ok())
+        .map(Kilograms)
+}
+
+/// The item can be turned any way, so it fits if its sides, smallest to largest, each fit the
+/// matching side of the cargo space.
+fn fits(item: &Dimensions, space: &Dimensions) -> bool {
+    let sorted = |dimensions: &Dimensions| {
+        let mut sides = [dimensions.width.0, dimensions.height.0, dimensions.length.0];
+        sides.sort_by(f32::total_cmp);
+        sides
+    };
+
+    sorted(item)
+        .iter()
+        .zip(sorted(space))
+        .all(|(side, space)| *side <= space)
+}
+
+fn in_country(phone_number: &PhoneNumber, country: &Country) -> bool {
+    phone_number.country().id() == Some(country.phone_country_id())
+}
@@ -0,0 +1,409 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use rusty_money::{
+    iso::{self, Currency},
+    Money, MoneyError,
+};
+use serde::Deserialize;
+use serde_json::{from_value, Error as SerdeJsonError, Value};
+use serde_with::serde_as;
+use thiserror::Error as ThisError;
+
+use crate::{money::MoneyAmount, DeliveryId, DeliveryStatus, DriverId, DriverInfo, PriceBreakdown};
+
+mod filter;
+mod sequencer;
+
+pub use filter::WebhookFilter;
+pub use sequencer::{
+    EventOrder, EventSequencer, MemorySequenceStore, SequencePoint, SequenceStore,
+};
+
+#[cfg(feature = "_webhook")]
+mod verifier;
+
+#[cfg(feature = "_webhook")]
+pub use verifier::{WebhookError, WebhookVerifier};
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "sqlx")]
+pub use self::sqlx::{StoredWebhook, WebhookStore, WebhookStoreError, WEBHOOK_MIGRATION};
+
+#[cfg(feature = "webhook-test")]
+pub mod test;
+
+#[cfg(feature = "webhook-server")]
+mod server;
+
+#[cfg(feature = "webhook-server")]
+pub use server::WebhookServer;
+
+#[cfg(feature = "axum")]
+mod axum;
+
+#[cfg(feature = "axum")]
+pub use self::axum::{LalamoveWebhook, WebhookRejection};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    pub api_key: String,
+    pub timestamp: i64,
+    pub signature: String,
+    pub event_id: String,
+    pub event_version: String,
+    #[serde(flatten)]
+    pub event: WebhookEvent,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "ApiWebhookEvent")]
+pub enum WebhookEvent {
+    OrderStatusChanged(OrderStatusChanged),
+    DriverAssigned(DriverAssigned),
+    OrderAmountChanged(OrderAmountChanged),
+    OrderReplaced(OrderReplaced),
+    OrderEdited(OrderEdited),
+    WalletBalanceChanged(WalletBalanceChanged),
+    #[serde(other)]
+    Other,
+}
+
+impl WebhookEvent {
+    pub fn delivery_id(&self) -> Option<&DeliveryId> {
+        use WebhookEvent as WE;
+
+        match self {
+            WE::OrderStatusChanged(event) => Some(&event.order_id),
+            WE::DriverAssigned(event) => Some(&event.order_id),
+            WE::OrderAmountChanged(event) => Some(&event.order_id),
+            WE::OrderReplaced(event) => Some(&event.order_id),
+            WE::OrderEdited(event) => Some(&event.order_id),
+            WE::WalletBalanceChanged(_) | WE::Other(_) => None,
+        }
+    }
+
+    pub fn status(&self) -> Option<&DeliveryStatus> {
+        match self {
+            WebhookEvent::OrderStatusChanged(event) => Some(&event.status),
+            _ => None,
+        }
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        use WebhookEvent as WE;
+
+        match self {
+            WE::OrderStatusChanged(event) => Some(event.updated_at),
+            WE::DriverAssigned(event) => Some(event.updated_at),
+            WE::OrderAmountChanged(event) => Some(event.updated_at),
+            WE::OrderReplaced(event) => Some(event.updated_at),
+            WE::OrderEdited(event) => Some(event.updated_at),
+            WE::WalletBalanceChanged(event) => Some(event.updated_at),
+            WE::Other(_) => None,
+        }
+    }
+
+    pub fn event_type(&self) -> &str {
+        use WebhookEvent as WE;
+
+        match self {
+            WE::OrderStatusChanged(_) => "ORDER_STATUS_CHANGED",
+            WE::DriverAssigned(_) => "DRIVER_ASSIGNED",
+            WE::OrderAmountChanged(_) => "ORDER_AMOUNT_CHANGED",
+            WE::OrderReplaced(_) => "ORDER_REPLACED",
+            WE::OrderEdited(_) => "ORDER_EDITED",
+            WE::WalletBalanceChanged(_) => "WALLET_BALANCE_CHANGED",
+            WE::Other(event_type) => event_type,
+        }
+    }
+}
+
+// Serde's adjacently tagged `#[serde(other)]` only accepts unknown events without a payload, and
+// every Lalamove event has one.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiWebhookEvent {
+    event_type: String,
+    #[serde(default)]
+    data: Value,
+}
+
+impl TryFrom<ApiWebhookEvent> for WebhookEvent {
+    type Error = SerdeJsonError;
+
+    fn try_from(event: ApiWebhookEvent) -> Result<Self, Self::Error> {
+        use WebhookEvent as WE;
+
+        let ApiWebhookEvent { event_type, data } = event;
+
+        Ok(match &*event_type {
+            "ORDER_STATUS_CHANGED" => WE::OrderStatusChanged(from_value(data)?),
+            "DRIVER_ASSIGNED" => WE::DriverAssigned(from_value(data)?),
+            "ORDER_AMOUNT_CHANGED" => WE::OrderAmountChanged(from_value(data)?),
+            "ORDER_REPLACED" => WE::OrderReplaced(from_value(data)?),
+            "ORDER_EDITED" => WE::OrderEdited(from_value(data)?),
+            "WALLET_BALANCE_CHANGED" => WE::WalletBalanceChanged(from_value(data)?),
+            _ => WE::Other(event_type),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "ApiOrderStatusChanged")]
+pub struct OrderStatusChanged {
+    pub order_id: DeliveryId,
+    pub status: DeliveryStatus,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "ApiDriverAssigned")]
+pub struct DriverAssigned {
+    pub order_id: DeliveryId,
+    pub driver_id: DriverId,
+    pub driver: DriverInfo,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "ApiOrderAmountChanged")]
+pub struct OrderAmountChanged {
+    pub order_id: DeliveryId,
+    pub total: Money<'static, Currency>,
+    pub price_breakdown: PriceBreakdown,
+    pub previous_price_breakdown: Option<PriceBreakdown>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "ApiOrderReplaced")]
+pub struct OrderReplaced {
+    pub order_id: DeliveryId,
+    pub previous_order_id: DeliveryId,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "ApiOrderEdited")]
+pub struct OrderEdited {
+    pub order_id: DeliveryId,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletBalanceChanged {
+    #[serde_as(as = "MoneyAmount")]
+    pub balance: Money<'static, Currency>,
+    #[serde_as(as = "Option<MoneyAmount>")]
+    #[serde(default)]
+    pub threshold: Option<Money<'static, Currency>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WalletBalanceChanged {
+    pub fn is_below_threshold(&self) -> bool {
+        self.threshold.as_ref().is_some_and(|threshold| {
+            threshold.currency() == self.balance.currency()
+                && self.balance.amount() < threshold.amount()
+        })
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum InvalidWebhookAmount {
+    #[error("Couldn't find a currency that matched '{0}'.")]
+    CurrencyNotFound(String),
+    #[error(transparent)]
+    InvalidAmount(#[from] MoneyError),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiOrderStatusChanged {
+    order: ApiOrder,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ApiOrderStatusChanged> for OrderStatusChanged {
+    fn from(event: ApiOrderStatusChanged) -> Self {
+        OrderStatusChanged {
+            order_id: event.order.order_id,
+            status: event.order.status,
+            updated_at: event.updated_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiOrder {
+    order_id: DeliveryId,
+    status: DeliveryStatus,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiDriverAssigned {
+    order: ApiOrderId,
+    driver: ApiDriver,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ApiDriverAssigned> for DriverAssigned {
+    fn from(event: ApiDriverAssigned) -> Self {
+        DriverAssigned {
+            order_id: event.order.order_id,
+            driver_id: event.driver.driver_id,
+            driver: DriverInfo {
+                name: event.driver.name,
+                masked_phone: event.driver.phone,
+                plate_number: event.driver.plate_number,
+            },
+            updated_at: event.updated_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiDriver {
+    driver_id: DriverId,
+    name: String,
+    phone: String,
+    plate_number: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiOrderId {
+    order_id: DeliveryId,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiOrderAmountChanged {
+    order: ApiPricedOrder,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<ApiOrderAmountChanged> for OrderAmountChanged {
+    type Error = InvalidWebhookAmount;
+
+    fn try_from(event: ApiOrderAmountChanged) -> Result<Self, Self::Error> {
+        let price_breakdown = PriceBreakdown::try_from(event.order.price_breakdown)?;
+        let previous_price_breakdown = event
+            .order
+            .previous_price_breakdown
+            .map(PriceBreakdown::try_from)
+            .transpose()?;
+
+        Ok(OrderAmountChanged {
+            order_id: event.order.order_id,
+            total: price_breakdown.total.clone(),
+            price_breakdown,
+            previous_price_breakdown,
+            updated_at: event.updated_at,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiPricedOrder {
+    order_id: DeliveryId,
+    price_breakdown: ApiPriceBreakdown,
+    #[serde(default)]
+    previous_price_breakdown: Option<ApiPriceBreakdown>,
+}
+
+// Shared with the client, which reads the same breakdown out of quotations and order details.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ApiPriceBreakdown {
+    pub(crate) total: String,
+    pub(crate) currency: String,
+    #[serde(flatten)]
+    pub(crate) components: BTreeMap<String, Value>,
+}
+
+impl ApiPriceBreakdown {
+    pub(crate) fn price_breakdown(
+        &self,
+        currency: &'static Currency,
+    ) -> Result<PriceBreakdown, MoneyError> {
+        let component = |key: &str| {
+            self.components
+                .get(key)
+                .and_then(Value::as_str)
+                .map(|amount| Money::from_str(amount, currency))
+                .transpose()
+        };
+
+        Ok(PriceBreakdown {
+            base: component("base")?,
+            extra_mileage: component("extraMileage")?,
+            surcharge: component("surcharge")?,
+            priority_fee: component("priorityFee")?,
+            total_exclude_priority_fee: component("totalExcludePriorityFee")?,
+            total: Money::from_str(&self.total, currency)?,
+            currency,
+        })
+    }
+}
+
+impl TryFrom<ApiPriceBreakdown> for PriceBreakdown {
+    type Error = InvalidWebhookAmount;
+
+    fn try_from(breakdown: ApiPriceBreakdown) -> Result<Self, Self::Error> {
+        let currency = iso::find(&breakdown.currency)
+            .ok_or_else(|| InvalidWebhookAmount::CurrencyNotFound(breakdown.currency.clone()))?;
+
+        Ok(breakdown.price_breakdown(currency)?)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiOrderReplaced {
+    order: ApiReplacedOrder,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ApiOrderReplaced> for OrderReplaced {
+    fn from(event: ApiOrderReplaced) -> Self {
+        OrderReplaced {
+            order_id: event.order.order_id,
+            previous_order_id: event.order.previous_order_id,
+            updated_at: event.updated_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiReplacedOrder {
+    order_id: DeliveryId,
+    previous_order_id: DeliveryId,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiOrderEdited {
+    order: ApiOrderId,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ApiOrderEdited> for OrderEdited {
+    fn from(event: ApiOrderEdited) -> Self {
+        OrderEdited {
+            order_id: event.order.order_id,
+            updated_at: event.updated_at,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use axum::{
+    body::{Bytes, HttpBody},
+    extract::{rejection::BytesRejection, FromRef, FromRequest, OriginalUri},
+    http::{Request, StatusCode},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use serde::de::DeserializeOwned;
+
+use crate::webhook::{WebhookError, WebhookVerifier};
+
+#[derive(Debug, Clone)]
+pub struct LalamoveWebhook<T>(pub T);
+
+#[async_trait]
+impl<S, B, T> FromRequest<S, B> for LalamoveWebhook<T>
+where
+    T: DeserializeOwned,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+    WebhookVerifier: FromRef<S>,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(request: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let verifier = WebhookVerifier::from_ref(state);
+        // Lalamove signs the full path, which a nested router strips from `request.uri()`.
+        let path = match request.extensions().get::<OriginalUri>() {
+            Some(OriginalUri(uri)) => uri.path().to_string(),
+            None => request.uri().path().to_string(),
+        };
+        let body = Bytes::from_request(request, state).await?;
+
+        Ok(LalamoveWebhook(verifier.verify(&path, &body)?))
+    }
+}
+
+#[derive(Debug)]
+pub enum WebhookRejection {
+    Body(BytesRejection),
+    Invalid(WebhookError),
+}
+
+impl From<BytesRejection> for WebhookRejection {
+    fn from(rejection: BytesRejection) -> Self {
+        WebhookRejection::Body(rejection)
+    }
+}
+
+impl From<WebhookError> for WebhookRejection {
+    fn from(error: WebhookError) -> Self {
+        WebhookRejection::Invalid(error)
+    }
+}
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        use WebhookError as WE;
+        use WebhookRejection as WR;
+
+        match self {
+            WR::Body(rejection) => rejection.into_response(),
+            WR::Invalid(error @ WE::SerdeJsonError(_)) => {
+                (StatusCode::BAD_REQUEST, error.to_string()).into_response()
+            }
+            WR::Invalid(error @ (WE::InvalidSignature | WE::Expired)) => {
+                (StatusCode::UNAUTHORIZED, error.to_string()).into_response()
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "webhook-test"))]
+mod tests {
+    use axum::{
+        extract::{FromRequest, OriginalUri},
+        http::StatusCode,
+        response::IntoResponse,
+    };
+
+    use crate::webhook::{
+        test::{signed_webhook, SampleEvent},
+        LalamoveWebhook, Webhook, WebhookEvent, WebhookVerifier,
+    };
+
+    const API_SECRET: &str = "sk_test_sample";
+
+    #[tokio::test]
+    async fn verifies_against_the_original_uri() {
+        let verifier = WebhookVerifier::new(API_SECRET);
+
+        let mut request =
+            signed_webhook(API_SECRET, "/hooks/lalamove", SampleEvent::DriverAssigned);
+        let original_uri = request.uri().clone();
+
+        *request.uri_mut() = "/lalamove".parse().unwrap();
+        request.extensions_mut().insert(OriginalUri(original_uri));
+
+        let LalamoveWebhook(webhook) = LalamoveWebhook::<Webhook>::from_request(request, &verifier)
+            .await
+            .unwrap();
+
+        assert!(matches!(webhook.event, WebhookEvent::DriverAssigned(_)));
+    }
+
+    #[tokio::test]
+    async fn rejects_tampered_webhooks() {
+        let verifier = WebhookVerifier::new(API_SECRET);
+
+        let request = signed_webhook(API_SECRET, "/hooks/lalamove", SampleEvent::OrderEdited)
+            .map(|body| body.replace("107900701184", "107900701185"));
+
+        let rejection = LalamoveWebhook::<Webhook>::from_request(request, &verifier)
+            .await
+            .unwrap_err();
+
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED);
+    }
+}
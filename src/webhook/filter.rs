@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use crate::{webhook::Webhook, DeliveryId};
+
+/// Decides which webhooks are worth handing to the application.
+///
+/// An empty filter lets everything through. The delivery allowlist only applies to events that
+/// belong to an order, so events like wallet balance changes are only filtered by their type.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookFilter {
+    event_types: Option<HashSet<String>>,
+    deliveries: Option<HashSet<DeliveryId>>,
+}
+
+impl WebhookFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_event_types(
+        mut self,
+        event_types: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.event_types = Some(event_types.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn with_deliveries(mut self, deliveries: impl IntoIterator<Item = DeliveryId>) -> Self {
+        self.deliveries = Some(deliveries.into_iter().collect());
+        self
+    }
+
+    pub fn matches(&self, webhook: &Webhook) -> bool {
+        let event = &webhook.event;
+
+        let type_allowed = self
+            .event_types
+            .as_ref()
+            .map_or(true, |event_types| event_types.contains(event.event_type()));
+
+        let delivery_allowed = match (&self.deliveries, event.delivery_id()) {
+            (Some(deliveries), Some(delivery)) => deliveries.contains(delivery),
+            _ => true,
+        };
+
+        type_allowed && delivery_allowed
+    }
+}
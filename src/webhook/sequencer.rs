@@ -0,0 +1,140 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::{webhook::Webhook, DeliveryId, DeliveryStatus};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequencePoint {
+    pub event_id: String,
+    pub event_type: String,
+    pub updated_at: DateTime<Utc>,
+    pub status: Option<DeliveryStatus>,
+}
+
+impl SequencePoint {
+    pub fn order_after(&self, latest: &SequencePoint) -> EventOrder {
+        if latest.event_id == self.event_id
+            || (latest.updated_at == self.updated_at
+                && latest.event_type == self.event_type
+                && latest.status == self.status)
+        {
+            return EventOrder::Duplicate;
+        }
+
+        if self.updated_at < latest.updated_at {
+            return EventOrder::Stale;
+        }
+
+        EventOrder::Fresh
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventOrder {
+    Fresh,
+    Stale,
+    Duplicate,
+}
+
+#[async_trait]
+pub trait SequenceStore: Send + Sync {
+    async fn latest(&self, delivery: &DeliveryId) -> Option<SequencePoint>;
+
+    /// Stores `next` only if the latest point for the delivery is still `current`, so that
+    /// several instances sharing a store can't both accept the same event.
+    async fn compare_and_set(
+        &self,
+        delivery: &DeliveryId,
+        current: Option<&SequencePoint>,
+        next: SequencePoint,
+    ) -> bool;
+}
+
+#[derive(Debug, Default)]
+pub struct MemorySequenceStore {
+    points: Mutex<HashMap<DeliveryId, SequencePoint>>,
+}
+
+#[async_trait]
+impl SequenceStore for MemorySequenceStore {
+    async fn latest(&self, delivery: &DeliveryId) -> Option<SequencePoint> {
+        self.points
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(delivery)
+            .cloned()
+    }
+
+    async fn compare_and_set(
+        &self,
+        delivery: &DeliveryId,
+        current: Option<&SequencePoint>,
+        next: SequencePoint,
+    ) -> bool {
+        let mut points = self.points.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if points.get(delivery) != current {
+            return false;
+        }
+
+        points.insert(delivery.clone(), next);
+        true
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct EventSequencer<S: SequenceStore = MemorySequenceStore> {
+    store: S,
+}
+
+impl EventSequencer {
+    pub fn new() -> Self {
+        EventSequencer::default()
+    }
+}
+
+impl<S: SequenceStore> EventSequencer<S> {
+    pub fn with_store(store: S) -> Self {
+        EventSequencer { store }
+    }
+
+    pub async fn observe(&self, webhook: &Webhook) -> EventOrder {
+        let (Some(delivery), Some(updated_at)) =
+            (webhook.event.delivery_id(), webhook.event.updated_at())
+        else {
+            return EventOrder::Fresh;
+        };
+
+        let point = SequencePoint {
+            event_id: webhook.event_id.clone(),
+            event_type: webhook.event.event_type().to_string(),
+            updated_at,
+            status: webhook.event.status().cloned(),
+        };
+
+        loop {
+            let latest = self.store.latest(delivery).await;
+
+            if let Some(latest) = &latest {
+                let order = point.order_after(latest);
+
+                if order != EventOrder::Fresh {
+                    return order;
+                }
+            }
+
+            if self
+                .store
+                .compare_and_set(delivery, latest.as_ref(), point.clone())
+                .await
+            {
+                return EventOrder::Fresh;
+            }
+        }
+    }
+}
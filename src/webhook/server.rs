@@ -0,0 +1,92 @@
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
+
+use hyper::{
+    body::to_bytes,
+    service::{make_service_fn, service_fn},
+    Body, Error as HyperError, Method, Request, Response, Server, StatusCode,
+};
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+
+use crate::webhook::{Webhook, WebhookError, WebhookFilter, WebhookVerifier};
+
+pub struct WebhookServer {
+    address: SocketAddr,
+    verifier: WebhookVerifier,
+    filter: WebhookFilter,
+}
+
+impl WebhookServer {
+    pub fn new(address: SocketAddr, verifier: WebhookVerifier) -> Self {
+        WebhookServer {
+            address,
+            verifier,
+            filter: WebhookFilter::new(),
+        }
+    }
+
+    /// Webhooks that don't match the filter are acknowledged without being sent to the receiver.
+    pub fn with_filter(mut self, filter: WebhookFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    // Binding and spawning both need a Tokio runtime, so this has to be awaited inside one.
+    pub async fn listen(self) -> Result<Receiver<Webhook>, HyperError> {
+        const CHANNEL_CAPACITY: usize = 64;
+
+        let (sender, receiver) = channel(CHANNEL_CAPACITY);
+        let verifier = Arc::new(self.verifier);
+        let filter = Arc::new(self.filter);
+
+        let server = Server::try_bind(&self.address)?.serve(make_service_fn(move |_| {
+            let verifier = verifier.clone();
+            let filter = filter.clone();
+            let sender = sender.clone();
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle(request, verifier.clone(), filter.clone(), sender.clone())
+                }))
+            }
+        }));
+
+        tokio::spawn(server);
+
+        Ok(receiver)
+    }
+}
+
+async fn handle(
+    request: Request<Body>,
+    verifier: Arc<WebhookVerifier>,
+    filter: Arc<WebhookFilter>,
+    sender: Sender<Webhook>,
+) -> Result<Response<Body>, Infallible> {
+    if request.method() != Method::POST {
+        return Ok(respond(StatusCode::METHOD_NOT_ALLOWED));
+    }
+
+    let path = request.uri().path().to_string();
+
+    let Ok(body) = to_bytes(request.into_body()).await else {
+        return Ok(respond(StatusCode::BAD_REQUEST));
+    };
+
+    Ok(respond(match verifier.verify::<Webhook>(&path, &body) {
+        Ok(webhook) => {
+            if filter.matches(&webhook) {
+                let _ = sender.send(webhook).await;
+            }
+
+            StatusCode::OK
+        }
+        Err(WebhookError::SerdeJsonError(_)) => StatusCode::BAD_REQUEST,
+        Err(WebhookError::InvalidSignature | WebhookError::Expired) => StatusCode::UNAUTHORIZED,
+    }))
+}
+
+fn respond(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+    response
+}
@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use serde_json::{from_slice, from_value, to_value, Error as SerdeJsonError, Value};
+use sqlx::{types::Json, Error as SqlxError, Executor, PgPool, Row};
+use thiserror::Error as ThisError;
+
+use crate::webhook::Webhook;
+
+pub const WEBHOOK_MIGRATION: &str = "
+CREATE TABLE IF NOT EXISTS lalamove_webhook_events (
+    id BIGSERIAL PRIMARY KEY,
+    event_id TEXT NOT NULL UNIQUE,
+    delivery_id TEXT,
+    status TEXT,
+    payload JSONB NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS lalamove_webhook_events_delivery_id
+    ON lalamove_webhook_events (delivery_id);
+";
+
+#[derive(Debug, Clone)]
+pub struct WebhookStore {
+    pool: PgPool,
+}
+
+#[derive(Debug, Clone)]
+pub struct StoredWebhook {
+    pub webhook: Webhook,
+    pub received_at: DateTime<Utc>,
+    /// Whether an event with the same id had already been recorded, in which case `received_at` is
+    /// when the first copy arrived.
+    pub duplicate: bool,
+}
+
+impl WebhookStore {
+    pub fn new(pool: PgPool) -> Self {
+        WebhookStore { pool }
+    }
+
+    pub async fn migrate(&self) -> Result<(), WebhookStoreError> {
+        self.pool.execute(WEBHOOK_MIGRATION).await?;
+        Ok(())
+    }
+
+    /// Stores the raw payload as it is, without checking its signature, so only call this with
+    /// payloads that have already been through `WebhookVerifier::verify`.
+    pub async fn record(&self, payload: &[u8]) -> Result<StoredWebhook, WebhookStoreError> {
+        let webhook = from_slice::<Webhook>(payload)?;
+        let payload = from_slice::<Value>(payload)?;
+
+        let status = match webhook.event.status() {
+            Some(status) => to_value(status)?.as_str().map(str::to_string),
+            None => None,
+        };
+
+        // `DO NOTHING` wouldn't return the existing row, so the conflict rewrites the event id to
+        // itself instead. `xmax` is only zero for rows this statement inserted.
+        let row = sqlx::query(
+            "INSERT INTO lalamove_webhook_events
+                (event_id, delivery_id, status, payload, received_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (event_id) DO UPDATE SET event_id = EXCLUDED.event_id
+            RETURNING received_at, xmax = 0 AS inserted",
+        )
+        .bind(&webhook.event_id)
+        .bind(
+            webhook
+                .event
+                .delivery_id()
+                .map(|delivery| delivery.to_string()),
+        )
+        .bind(status)
+        .bind(Json(payload))
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(StoredWebhook {
+            webhook,
+            received_at: row.try_get("received_at")?,
+            duplicate: !row.try_get::<bool, _>("inserted")?,
+        })
+    }
+
+    pub async fn replay(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<StoredWebhook>, WebhookStoreError> {
+        sqlx::query(
+            "SELECT payload, received_at FROM lalamove_webhook_events
+            WHERE received_at >= $1
+            ORDER BY received_at, id",
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|row| {
+            let Json(payload) = row.try_get::<Json<Value>, _>("payload")?;
+
+            Ok(StoredWebhook {
+                webhook: from_value(payload)?,
+                received_at: row.try_get("received_at")?,
+                duplicate: false,
+            })
+        })
+        .collect()
+    }
+}
+
+#[derive(Debug, ThisError)]
+pub enum WebhookStoreError {
+    #[error(transparent)]
+    SqlxError(#[from] SqlxError),
+    #[error(transparent)]
+    SerdeJsonError(#[from] SerdeJsonError),
+}
@@ -0,0 +1,143 @@
+use chrono::Utc;
+use http::{header::CONTENT_TYPE, Method, Request};
+use serde_json::{json, to_string, Value};
+
+use crate::signing::sign;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleEvent {
+    OrderStatusChanged,
+    DriverAssigned,
+    OrderAmountChanged,
+    OrderReplaced,
+    OrderEdited,
+    WalletBalanceChanged,
+}
+
+impl SampleEvent {
+    pub const ALL: [SampleEvent; 6] = [
+        SampleEvent::OrderStatusChanged,
+        SampleEvent::DriverAssigned,
+        SampleEvent::OrderAmountChanged,
+        SampleEvent::OrderReplaced,
+        SampleEvent::OrderEdited,
+        SampleEvent::WalletBalanceChanged,
+    ];
+
+    pub const fn event_type(&self) -> &'static str {
+        use SampleEvent as SE;
+
+        match self {
+            SE::OrderStatusChanged => "ORDER_STATUS_CHANGED",
+            SE::DriverAssigned => "DRIVER_ASSIGNED",
+            SE::OrderAmountChanged => "ORDER_AMOUNT_CHANGED",
+            SE::OrderReplaced => "ORDER_REPLACED",
+            SE::OrderEdited => "ORDER_EDITED",
+            SE::WalletBalanceChanged => "WALLET_BALANCE_CHANGED",
+        }
+    }
+
+    fn data(&self) -> Value {
+        use SampleEvent as SE;
+
+        let updated_at = Utc::now().to_rfc3339();
+
+        match self {
+            SE::OrderStatusChanged => json!({
+                "order": { "orderId": "107900701184", "status": "PICKED_UP" },
+                "updatedAt": updated_at,
+            }),
+            SE::DriverAssigned => json!({
+                "order": { "orderId": "107900701184" },
+                "driver": {
+                    "driverId": "80557",
+                    "name": "Juan",
+                    "phone": "+63900****512",
+                    "plateNumber": "ABC 1234",
+                },
+                "updatedAt": updated_at,
+            }),
+            SE::OrderAmountChanged => json!({
+                "order": {
+                    "orderId": "107900701184",
+                    "priceBreakdown": {
+                        "base": "90",
+                        "priorityFee": "20",
+                        "totalExcludePriorityFee": "90",
+                        "total": "110",
+                        "currency": "PHP",
+                    },
+                    "previousPriceBreakdown": {
+                        "base": "90",
+                        "totalExcludePriorityFee": "90",
+                        "total": "90",
+                        "currency": "PHP",
+                    },
+                },
+                "updatedAt": updated_at,
+            }),
+            SE::OrderReplaced => json!({
+                "order": { "orderId": "107900701185", "previousOrderId": "107900701184" },
+                "updatedAt": updated_at,
+            }),
+            SE::OrderEdited => json!({
+                "order": { "orderId": "107900701184" },
+                "updatedAt": updated_at,
+            }),
+            SE::WalletBalanceChanged => json!({
+                "balance": { "amount": "1500", "currency": "PHP" },
+                "threshold": { "amount": "2000", "currency": "PHP" },
+                "updatedAt": updated_at,
+            }),
+        }
+    }
+}
+
+pub fn signed_webhook(api_secret: &str, path: &str, event: SampleEvent) -> Request<String> {
+    let timestamp = Utc::now().timestamp();
+    let data = event.data().to_string();
+
+    let signature = sign(api_secret, timestamp, &Method::POST, path, &data);
+
+    let quoted = |value: &str| to_string(value).expect("Strings should always serialize.");
+
+    let body = format!(
+        "{{\"apiKey\":{},\"timestamp\":{timestamp},\"signature\":{},\"eventId\":{},\"eventType\":{},\"eventVersion\":\"v3\",\"data\":{data}}}",
+        quoted("pk_test_sample"),
+        quoted(&signature),
+        quoted(&format!("sample-{}-{timestamp}", event.event_type())),
+        quoted(event.event_type()),
+    );
+
+    Request::builder()
+        .method(Method::POST)
+        .uri(path)
+        .header(CONTENT_TYPE, "application/json")
+        .body(body)
+        .expect("This should have been a valid request.")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::webhook::{Webhook, WebhookVerifier};
+
+    use super::{signed_webhook, SampleEvent};
+
+    #[test]
+    fn fixtures_pass_verification() {
+        const API_SECRET: &str = "sk_test_sample";
+        const PATH: &str = "/webhooks/lalamove";
+
+        let verifier = WebhookVerifier::new(API_SECRET);
+
+        for event in SampleEvent::ALL {
+            let request = signed_webhook(API_SECRET, PATH, event);
+
+            let webhook = verifier
+                .verify::<Webhook>(PATH, request.body().as_bytes())
+                .unwrap();
+
+            assert_eq!(webhook.event.event_type(), event.event_type());
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use chrono::{Duration, TimeZone, Utc};
+use hex::decode;
+use hmac::Mac;
+use http::Method;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{from_slice, value::RawValue, Error as SerdeJsonError};
+use thiserror::Error as ThisError;
+
+use crate::signing::mac;
+
+#[derive(Debug, Clone)]
+pub struct WebhookVerifier {
+    api_secret: String,
+    max_age: Duration,
+}
+
+impl WebhookVerifier {
+    pub fn new(api_secret: impl Into<String>) -> Self {
+        const DEFAULT_MAX_AGE_MINUTES: i64 = 5;
+
+        WebhookVerifier {
+            api_secret: api_secret.into(),
+            max_age: Duration::minutes(DEFAULT_MAX_AGE_MINUTES),
+        }
+    }
+
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    pub fn verify<T: DeserializeOwned>(&self, path: &str, body: &[u8]) -> Result<T, WebhookError> {
+        let envelope = from_slice::<SignedEnvelope>(body)?;
+
+        let sent_at = Utc
+            .timestamp_opt(envelope.timestamp, 0)
+            .single()
+            .ok_or(WebhookError::Expired)?;
+
+        if (Utc::now() - sent_at).abs() > self.max_age {
+            return Err(WebhookError::Expired);
+        }
+
+        let timestamp = envelope.timestamp;
+        let data = envelope.data.get();
+        let mac = mac(&self.api_secret, timestamp, &Method::POST, path, data);
+
+        let signature = decode(&envelope.signature).map_err(|_| WebhookError::InvalidSignature)?;
+
+        mac.verify_slice(&signature)
+            .map_err(|_| WebhookError::InvalidSignature)?;
+
+        Ok(from_slice(body)?)
+    }
+}
+
+#[derive(Deserialize)]
+struct SignedEnvelope<'a> {
+    timestamp: i64,
+    signature: String,
+    #[serde(borrow)]
+    data: &'a RawValue,
+}
+
+#[derive(Debug, ThisError)]
+pub enum WebhookError {
+    #[error(transparent)]
+    SerdeJsonError(#[from] SerdeJsonError),
+    #[error("The webhook's signature didn't match its contents.")]
+    InvalidSignature,
+    #[error("The webhook's timestamp was outside of the allowed window.")]
+    Expired,
+}